@@ -0,0 +1,133 @@
+use std::io::IoSlice;
+use crate::writer::{validate_header_line, HttpBytesWriter, WritingHttpErrors};
+
+/// encoder for streaming bodies of unknown length with chunked transfer-encoding
+///
+/// # Example
+/// ```
+/// use water_http_utils::body::ChunkedEncoder;
+/// let mut encoder = ChunkedEncoder::new();
+/// let mut bytes = vec![];
+/// encoder.write_chunk(b"hello",&mut bytes).unwrap();
+/// encoder.write_chunk(b" world",&mut bytes).unwrap();
+/// encoder.write_last_chunk(&[("X-Checksum","42")],&mut bytes).unwrap();
+/// assert_eq!(bytes,b"5\r\nhello\r\n6\r\n world\r\n0\r\nX-Checksum: 42\r\n\r\n");
+/// ```
+#[derive(Debug,Default)]
+pub struct ChunkedEncoder {
+    finished:bool,
+}
+
+/// single encoded chunk which references the chunk data without copying it
+#[derive(Debug)]
+pub struct EncodedChunk<'a> {
+    size_line:[u8;18],
+    size_line_start:usize,
+    data:&'a [u8],
+}
+
+impl<'a> EncodedChunk<'a> {
+
+    fn new(data:&'a [u8])->EncodedChunk<'a>{
+        // 16 hex digits are enough for usize::MAX followed by CRLF
+        let mut size_line = [0_u8;18];
+        let mut start = 16_usize;
+        size_line[16] = b'\r';
+        size_line[17] = b'\n';
+        let mut size = data.len();
+        loop {
+            start -= 1;
+            size_line[start] = b"0123456789abcdef"[size % 16];
+            size /= 16;
+            if size == 0 { break }
+        }
+        EncodedChunk {
+            size_line,
+            size_line_start:start,
+            data
+        }
+    }
+
+    /// returning total length of encoded chunk
+    pub fn written_length(&self)->usize{
+        if self.data.is_empty() { return 0 }
+        self.size_line.len() - self.size_line_start + self.data.len() + 2
+    }
+
+    /// serializing chunk into any [`HttpBytesWriter`]
+    pub fn write_into<W:HttpBytesWriter>(&self,writer:&mut W)->Result<(),WritingHttpErrors>{
+        // empty chunk is the terminating chunk ,so it must never be written as data
+        if self.data.is_empty() { return Ok(()) }
+        writer.write_bytes(&self.size_line[self.size_line_start..])?;
+        writer.write_bytes(self.data)?;
+        writer.write_bytes(b"\r\n")
+    }
+
+    /// pushing references of chunk parts into the given list for vectored writes
+    pub fn write_io_slices<'s>(&'s self,slices:&mut Vec<IoSlice<'s>>){
+        if self.data.is_empty() { return }
+        slices.push(IoSlice::new(&self.size_line[self.size_line_start..]));
+        slices.push(IoSlice::new(self.data));
+        slices.push(IoSlice::new(b"\r\n"));
+    }
+}
+
+impl ChunkedEncoder {
+
+    /// creating new chunked encoder
+    pub fn new()->ChunkedEncoder{
+        ChunkedEncoder {
+            finished:false
+        }
+    }
+
+    /// checking if the terminating chunk was written
+    pub fn is_finished(&self)->bool{
+        self.finished
+    }
+
+    /// encoding the given data as a single chunk ,empty data produces nothing
+    /// because empty chunk terminates the body
+    ///
+    /// like [`ChunkedEncoder::write_chunk`] it fails with [`WritingHttpErrors::BodyAlreadyFinished`]
+    /// after the terminating chunk was written
+    pub fn chunk<'a>(&self,data:&'a [u8])->Result<EncodedChunk<'a>,WritingHttpErrors>{
+        if self.finished { return WritingHttpErrors::BodyAlreadyFinished.into() }
+        Ok(EncodedChunk::new(data))
+    }
+
+    /// writing the given data as a single chunk into any [`HttpBytesWriter`]
+    pub fn write_chunk<W:HttpBytesWriter>(&mut self,data:&[u8],writer:&mut W)->Result<(),WritingHttpErrors>{
+        self.chunk(data)?.write_into(writer)
+    }
+
+    /// writing the terminating zero chunk with the given trailer fields and the final empty line
+    ///
+    /// encoder is only finished when all parts were written ,so failing writes like
+    /// [`WritingHttpErrors::BufferTooSmall`] could be retried with another writer
+    pub fn write_last_chunk<W:HttpBytesWriter>(&mut self,trailers:&[(&str,&str)],writer:&mut W)->Result<(),WritingHttpErrors>{
+        self.last_chunk_parts(trailers,|part| writer.write_bytes(part))
+    }
+
+    /// pushing references of the terminating chunk parts into the given list for vectored writes
+    pub fn write_last_chunk_io_slices<'s>(&mut self,trailers:&'s [(&'s str,&'s str)],slices:&mut Vec<IoSlice<'s>>)->Result<(),WritingHttpErrors>{
+        self.last_chunk_parts(trailers,|part|{ slices.push(IoSlice::new(part)); Ok(())})
+    }
+
+    fn last_chunk_parts<'s>(&mut self,trailers:&'s [(&'s str,&'s str)],mut f:impl FnMut(&'s [u8])->Result<(),WritingHttpErrors>)->Result<(),WritingHttpErrors>{
+        if self.finished { return WritingHttpErrors::BodyAlreadyFinished.into() }
+        for (key,value) in trailers {
+            validate_header_line(key,value.as_bytes())?;
+        }
+        f(b"0\r\n")?;
+        for (key,value) in trailers {
+            f(key.as_bytes())?;
+            f(b": ")?;
+            f(value.as_bytes())?;
+            f(b"\r\n")?;
+        }
+        f(b"\r\n")?;
+        self.finished = true;
+        Ok(())
+    }
+}
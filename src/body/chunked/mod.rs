@@ -0,0 +1,392 @@
+mod encoder;
+
+pub use encoder::*;
+use crate::body::DecodingChunkedErrors;
+use crate::config::global_config;
+use crate::utils::{is_field_value_byte, is_token_byte};
+
+/// max allowed length of a chunk size line including chunk extensions
+const MAX_CHUNK_SIZE_LINE_LENGTH:usize = 1024 * 4;
+
+#[derive(Debug,Clone,Copy,PartialEq)]
+enum ChunkedDecodingSteps {
+    Size,
+    Data(usize),
+    DataEnd,
+    Trailers,
+    Finished,
+}
+
+/// incremental decoder for chunked transfer-encoding body (RFC 9112 section 7.1)
+///
+/// the decoder keeps its state between calls ,so the caller feeds it with the bytes
+/// it did not consume yet and appends newly read bytes after them
+///
+/// # Example
+/// ```
+/// use water_http_utils::body::{ChunkedDecodeResult, ChunkedDecoder};
+/// let bytes = b"5\r\nhello\r\n6;ext=1\r\n world\r\n0\r\n\r\n";
+/// let mut decoder = ChunkedDecoder::new();
+/// let mut body = vec![];
+/// let mut position = 0;
+/// loop {
+///     match decoder.decode(&bytes[position..]).unwrap() {
+///         ChunkedDecodeResult::Data { data, consumed } => {
+///             body.extend_from_slice(data);
+///             position += consumed;
+///         }
+///         ChunkedDecodeResult::Finished { consumed, .. } => {
+///             position += consumed;
+///             break;
+///         }
+///     }
+/// }
+/// assert_eq!(body, b"hello world");
+/// assert_eq!(position, bytes.len());
+/// ```
+#[derive(Debug)]
+pub struct ChunkedDecoder {
+    step:ChunkedDecodingSteps,
+    body_size:usize,
+    max_body_size:Option<usize>,
+}
+
+/// result of single [`ChunkedDecoder::decode`] call
+#[derive(Debug,PartialEq)]
+pub enum ChunkedDecodeResult<'buf> {
+    /// decoded body bytes ,which could be empty when only chunk framing was consumed
+    Data {
+        /// decoded body bytes referenced from the input
+        data:&'buf [u8],
+        /// how many input bytes were consumed
+        consumed:usize,
+    },
+    /// the last chunk and the trailer section were decoded
+    Finished {
+        /// raw trailer section header lines ,each one ends with CRLF
+        trailers:&'buf [u8],
+        /// how many input bytes were consumed
+        consumed:usize,
+    },
+}
+
+impl Default for ChunkedDecoder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ChunkedDecoder {
+
+    /// creating new decoder limited with [`crate::config::HttpHConfigurations::max_body_size`]
+    pub fn new()->ChunkedDecoder{
+        Self::with_max_body_size(global_config().max_body_size)
+    }
+
+    /// creating new decoder with custom max decoded body size
+    pub fn with_max_body_size(max_body_size:Option<usize>)->ChunkedDecoder{
+        ChunkedDecoder {
+            step:ChunkedDecodingSteps::Size,
+            body_size:0,
+            max_body_size
+        }
+    }
+
+    /// returning total size of body chunks announced so far
+    pub fn body_size(&self)->usize{
+        self.body_size
+    }
+
+    /// checking if the last chunk and the trailer section were decoded
+    pub fn is_finished(&self)->bool{
+        self.step == ChunkedDecodingSteps::Finished
+    }
+
+    /// decoding the next part of the body from the bytes that were not consumed yet
+    ///
+    /// returns [`DecodingChunkedErrors::InsufficientDataSoReadMore`] only when nothing could be consumed
+    pub fn decode<'buf>(&mut self,bytes:&'buf [u8])->Result<ChunkedDecodeResult<'buf>,DecodingChunkedErrors>{
+        let mut consumed = 0_usize;
+        loop {
+            let rest = &bytes[consumed..];
+            match self.step {
+                ChunkedDecodingSteps::Size => {
+                    let line_end = match find_line_end(rest)? {
+                        None => {return read_more(consumed)}
+                        Some(i) => {i}
+                    };
+                    let size = parse_chunk_size(&rest[..line_end])?;
+                    consumed += line_end + 2;
+                    if size == 0 {
+                        self.step = ChunkedDecodingSteps::Trailers;
+                        continue;
+                    }
+                    let body_size = match self.body_size.checked_add(size) {
+                        None => {return DecodingChunkedErrors::DangerousInvalidFormat.into()}
+                        Some(s) => {s}
+                    };
+                    if let Some(max) = self.max_body_size {
+                        if body_size > max { return DecodingChunkedErrors::MaxBodySizeReachedOut.into() }
+                    }
+                    self.body_size = body_size;
+                    self.step = ChunkedDecodingSteps::Data(size);
+                }
+                ChunkedDecodingSteps::Data(remaining) => {
+                    if rest.is_empty() { return read_more(consumed) }
+                    let length = remaining.min(rest.len());
+                    self.step = if length == remaining {
+                        ChunkedDecodingSteps::DataEnd
+                    } else {
+                        ChunkedDecodingSteps::Data(remaining - length)
+                    };
+                    return Ok(ChunkedDecodeResult::Data { data:&rest[..length], consumed:consumed + length })
+                }
+                ChunkedDecodingSteps::DataEnd => {
+                    if rest.len() < 2 {
+                        if rest.first().is_some_and(|b| b != &b'\r') { return DecodingChunkedErrors::InvalidChunkFormat.into() }
+                        return read_more(consumed)
+                    }
+                    if &rest[..2] != b"\r\n" { return DecodingChunkedErrors::InvalidChunkFormat.into() }
+                    consumed += 2;
+                    self.step = ChunkedDecodingSteps::Size;
+                }
+                ChunkedDecodingSteps::Trailers => {
+                    let max_trailers_size = global_config().max_headers_size;
+                    // trailers are scanned again from their start until the empty line arrives
+                    // ,which is bounded by the max trailers size
+                    let mut line_start = 0_usize;
+                    loop {
+                        let Some(lf) = rest[line_start..].iter().position(|b| b == &b'\n').map(|i| i + line_start) else {
+                            if rest.len() >= max_trailers_size { return DecodingChunkedErrors::DangerousInvalidFormat.into() }
+                            return read_more(consumed)
+                        };
+                        if lf >= max_trailers_size + 2 { return DecodingChunkedErrors::DangerousInvalidFormat.into() }
+                        if lf == line_start || rest[lf - 1] != b'\r' { return DecodingChunkedErrors::InvalidChunkFormat.into() }
+                        let line = &rest[line_start..lf - 1];
+                        if line.is_empty() {
+                            self.step = ChunkedDecodingSteps::Finished;
+                            return Ok(ChunkedDecodeResult::Finished { trailers:&rest[..line_start], consumed:consumed + lf + 1 })
+                        }
+                        if !is_valid_trailer_line(line) { return DecodingChunkedErrors::InvalidChunkFormat.into() }
+                        line_start = lf + 1;
+                    }
+                }
+                ChunkedDecodingSteps::Finished => {
+                    return Ok(ChunkedDecodeResult::Finished { trailers:&[], consumed })
+                }
+            }
+        }
+    }
+}
+
+#[inline]
+fn read_more<'buf>(consumed:usize)->Result<ChunkedDecodeResult<'buf>,DecodingChunkedErrors>{
+    if consumed > 0 {
+        return Ok(ChunkedDecodeResult::Data { data:&[], consumed })
+    }
+    DecodingChunkedErrors::InsufficientDataSoReadMore.into()
+}
+
+/// returning the index of CR in the first CRLF of the given bytes if existed
+fn find_line_end(bytes:&[u8])->Result<Option<usize>,DecodingChunkedErrors>{
+    for (index,byte) in bytes.iter().enumerate() {
+        if index >= MAX_CHUNK_SIZE_LINE_LENGTH { return DecodingChunkedErrors::DangerousInvalidFormat.into() }
+        match byte {
+            b'\r' => {
+                return match bytes.get(index + 1) {
+                    None => {Ok(None)}
+                    Some(b'\n') => {Ok(Some(index))}
+                    Some(_) => {DecodingChunkedErrors::InvalidChunkFormat.into()}
+                }
+            }
+            b'\n' => {return DecodingChunkedErrors::InvalidChunkFormat.into()}
+            _ => {}
+        }
+    }
+    Ok(None)
+}
+
+/// checking trailer field line without its CRLF with the same rules as header field lines
+/// ,which is a token name followed by colon and field value bytes
+fn is_valid_trailer_line(line:&[u8])->bool{
+    let Some(colon) = line.iter().position(|b| b == &b':') else { return false };
+    colon > 0 && line[..colon].iter().all(|b| is_token_byte(*b)) && line[colon + 1..].iter().all(|b| is_field_value_byte(*b))
+}
+
+/// parsing chunk size line like `1a;name=value` without the CRLF
+fn parse_chunk_size(line:&[u8])->Result<usize,DecodingChunkedErrors>{
+    let mut size = 0_usize;
+    let mut digits = 0_usize;
+    for byte in line {
+        let digit = match byte {
+            b'0'..=b'9' => {byte - b'0'}
+            b'a'..=b'f' => {byte - b'a' + 10}
+            b'A'..=b'F' => {byte - b'A' + 10}
+            _ => {break}
+        };
+        size = match size.checked_mul(16) {
+            None => {return DecodingChunkedErrors::DangerousInvalidFormat.into()}
+            Some(s) => {s + digit as usize}
+        };
+        digits += 1;
+    }
+    if digits == 0 { return DecodingChunkedErrors::InvalidChunkFormat.into() }
+
+    // chunk extensions are ignored but still they should not contain control characters
+    let extensions = &line[digits..];
+    let extensions_start = extensions.iter().position(|b| b != &b' ' && b != &b'\t').unwrap_or(extensions.len());
+    let extensions = &extensions[extensions_start..];
+    if !extensions.is_empty() && (extensions[0] != b';' || extensions.iter().any(|b| b.is_ascii_control() && b != &b'\t')) {
+        return DecodingChunkedErrors::InvalidChunkFormat.into()
+    }
+    Ok(size)
+}
+
+
+#[cfg(test)]
+mod test {
+    use std::io::IoSlice;
+    use crate::body::{ChunkedDecodeResult, ChunkedDecoder, ChunkedEncoder, DecodingChunkedErrors};
+    use crate::writer::{SliceWriter, WritingHttpErrors};
+
+    fn decode_all(decoder:&mut ChunkedDecoder,bytes:&[u8])->Result<(Vec<u8>,Vec<u8>,usize),DecodingChunkedErrors>{
+        let mut body = vec![];
+        let mut position = 0;
+        loop {
+            match decoder.decode(&bytes[position..])? {
+                ChunkedDecodeResult::Data { data, consumed } => {
+                    body.extend_from_slice(data);
+                    position += consumed;
+                }
+                ChunkedDecodeResult::Finished { trailers, consumed } => {
+                    return Ok((body,trailers.to_vec(),position + consumed))
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_decoding_with_trailers() {
+        let bytes = b"4\r\nWiki\r\n5\r\npedia\r\nE\r\n in\r\n\r\nchunks.\r\n0\r\nExpires: never\r\nX-Sum: 1\r\n\r\nGET / HTTP/1.1";
+        let (body,trailers,consumed) = decode_all(&mut ChunkedDecoder::with_max_body_size(None),bytes).unwrap();
+        assert_eq!(body, b"Wikipedia in\r\n\r\nchunks.");
+        assert_eq!(trailers, b"Expires: never\r\nX-Sum: 1\r\n");
+        assert_eq!(&bytes[consumed..], b"GET / HTTP/1.1");
+    }
+
+    #[test]
+    fn test_decoding_byte_by_byte() {
+        let bytes = b"3;name=\"v\"\r\nabc\r\n10\r\n0123456789abcdef\r\n0\r\n\r\n";
+        let mut decoder = ChunkedDecoder::with_max_body_size(None);
+        let mut body = vec![];
+        let mut position = 0;
+        for end in 1..=bytes.len() {
+            loop {
+                match decoder.decode(&bytes[position..end]) {
+                    Ok(ChunkedDecodeResult::Data { data, consumed }) => {
+                        body.extend_from_slice(data);
+                        position += consumed;
+                        if consumed == 0 { break }
+                    }
+                    Ok(ChunkedDecodeResult::Finished { consumed, .. }) => {
+                        position += consumed;
+                        break
+                    }
+                    Err(DecodingChunkedErrors::InsufficientDataSoReadMore) => {break}
+                    Err(e) => {panic!("unexpected error {e:?}")}
+                }
+            }
+        }
+        assert!(decoder.is_finished());
+        assert_eq!(position, bytes.len());
+        assert_eq!(body, b"abc0123456789abcdef");
+    }
+
+    #[test]
+    fn test_max_body_size() {
+        let bytes = b"5\r\nhello\r\n6\r\n world\r\n0\r\n\r\n";
+        let result = decode_all(&mut ChunkedDecoder::with_max_body_size(Some(8)),bytes);
+        assert_eq!(result.err(), Some(DecodingChunkedErrors::MaxBodySizeReachedOut));
+    }
+
+    #[test]
+    fn test_invalid_chunks() {
+        for bytes in [&b"x\r\n"[..], b"5\r\nhelloXX", b"5 x\r\nhello\r\n", b"5\nhello\r\n"] {
+            let result = decode_all(&mut ChunkedDecoder::with_max_body_size(None),bytes);
+            assert_eq!(result.err(), Some(DecodingChunkedErrors::InvalidChunkFormat));
+        }
+        let result = decode_all(&mut ChunkedDecoder::with_max_body_size(None),b"fffffffffffffffff\r\n");
+        assert_eq!(result.err(), Some(DecodingChunkedErrors::DangerousInvalidFormat));
+    }
+
+    #[test]
+    fn test_invalid_trailers() {
+        for bytes in [
+            &b"0\r\nNoColon\r\n\r\n"[..],
+            b"0\r\nX: a\nY: b\r\n\r\n",
+            b"0\r\nX: a\x00b\r\n\r\n",
+            b"0\r\n\n\r\n\r\nGET",
+            b"0\r\n: empty-name\r\n\r\n",
+            b"0\r\nX : a\r\n\r\n",
+            b"0\r\nX: a\r\n folded\r\n\r\n",
+            b"0\r\nX: a\rY: b\r\n\r\n",
+        ] {
+            let result = decode_all(&mut ChunkedDecoder::with_max_body_size(None),bytes);
+            assert_eq!(result.err(), Some(DecodingChunkedErrors::InvalidChunkFormat), "{}", String::from_utf8_lossy(bytes));
+        }
+        let bytes = b"0\r\nX-A: 1\r\nX-B: caf\xe9 \"v\"\r\n\r\n";
+        for end in 3..bytes.len() {
+            assert_eq!(ChunkedDecoder::with_max_body_size(None).decode(&bytes[..end]), Ok(ChunkedDecodeResult::Data { data:&[], consumed:3 }));
+        }
+        let (_,trailers,consumed) = decode_all(&mut ChunkedDecoder::with_max_body_size(None),bytes).unwrap();
+        assert_eq!((trailers.as_slice(), consumed), (&bytes[3..bytes.len() - 2], bytes.len()));
+    }
+
+    #[test]
+    fn test_encoding_round_trip() {
+        let mut encoder = ChunkedEncoder::new();
+        let mut bytes = vec![];
+        let big = vec![b'x';300];
+        encoder.write_chunk(b"hello",&mut bytes).unwrap();
+        encoder.write_chunk(b"",&mut bytes).unwrap();
+        encoder.write_chunk(&big,&mut bytes).unwrap();
+        encoder.write_last_chunk(&[("Expires","never")],&mut bytes).unwrap();
+        assert!(bytes.starts_with(b"5\r\nhello\r\n12c\r\nxxx"));
+        assert_eq!(encoder.write_chunk(b"late",&mut bytes), Err(WritingHttpErrors::BodyAlreadyFinished));
+
+        let (body,trailers,consumed) = decode_all(&mut ChunkedDecoder::with_max_body_size(None),&bytes).unwrap();
+        assert_eq!(&body[..5], b"hello");
+        assert_eq!(body.len(), 305);
+        assert_eq!(trailers, b"Expires: never\r\n");
+        assert_eq!(consumed, bytes.len());
+    }
+
+    #[test]
+    fn test_encoding_io_slices() {
+        let mut encoder = ChunkedEncoder::new();
+        let mut slices:Vec<IoSlice> = vec![];
+        let chunk = encoder.chunk(b"abcdefghijklmnopq").unwrap();
+        chunk.write_io_slices(&mut slices);
+        encoder.write_last_chunk_io_slices(&[],&mut slices).unwrap();
+        let joined:Vec<u8> = slices.iter().flat_map(|s| s.iter().copied()).collect();
+        assert_eq!(joined, b"11\r\nabcdefghijklmnopq\r\n0\r\n\r\n");
+        assert_eq!(chunk.written_length(), 23);
+        assert_eq!(encoder.write_last_chunk(&[("Bad Name","v")],&mut vec![]), Err(WritingHttpErrors::BodyAlreadyFinished));
+        assert_eq!(ChunkedEncoder::new().write_last_chunk(&[("Bad Name","v")],&mut vec![]), Err(WritingHttpErrors::InvalidHeaderName));
+    }
+
+    #[test]
+    fn test_retrying_last_chunk() {
+        let mut encoder = ChunkedEncoder::new();
+        let mut buf = [0_u8;8];
+        let mut writer = SliceWriter::new(&mut buf);
+        assert_eq!(encoder.write_last_chunk(&[("Expires","never")],&mut writer), Err(WritingHttpErrors::BufferTooSmall));
+        assert!(!encoder.is_finished());
+        let mut bytes = vec![];
+        encoder.write_last_chunk(&[("Expires","never")],&mut bytes).unwrap();
+        assert_eq!(bytes, b"0\r\nExpires: never\r\n\r\n");
+        assert!(encoder.is_finished());
+        assert_eq!(encoder.chunk(b"late").err().unwrap(), WritingHttpErrors::BodyAlreadyFinished);
+        assert_eq!(encoder.write_chunk(b"late",&mut bytes), Err(WritingHttpErrors::BodyAlreadyFinished));
+    }
+}
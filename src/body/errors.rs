@@ -0,0 +1,85 @@
+use crate::request::CreatingRequestErrors;
+use crate::response::CreatingResponseErrors;
+
+/// defining errors while decoding chunked transfer-encoding body
+#[derive(Debug,PartialEq)]
+pub enum DecodingChunkedErrors {
+    /// if chunked payload not enough to continue decoding
+    InsufficientDataSoReadMore,
+    /// for invalid chunk size line ,missing CRLF after chunk data
+    /// or trailer lines which are not CRLF terminated field lines like headers
+    InvalidChunkFormat,
+    /// when chunk size overflows or chunk size line and trailers exceed the allowed limits
+    DangerousInvalidFormat,
+    /// when decoded body exceeds [`crate::config::HttpHConfigurations::max_body_size`]
+    MaxBodySizeReachedOut,
+}
+
+#[allow(clippy::from_over_into)]
+impl<T> Into<Result<T,DecodingChunkedErrors>> for  DecodingChunkedErrors {
+    fn into(self) -> Result<T, DecodingChunkedErrors> {
+        Err(self)
+    }
+}
+
+impl From<DecodingChunkedErrors> for CreatingRequestErrors {
+    fn from(value: DecodingChunkedErrors) -> Self {
+        match value {
+            DecodingChunkedErrors::InsufficientDataSoReadMore => {CreatingRequestErrors::InsufficientDataSoReadMore}
+            DecodingChunkedErrors::InvalidChunkFormat => {CreatingRequestErrors::InvalidHttpFormat}
+            DecodingChunkedErrors::DangerousInvalidFormat => {CreatingRequestErrors::DangerousInvalidHttpFormat(None)}
+            DecodingChunkedErrors::MaxBodySizeReachedOut => {CreatingRequestErrors::DangerousInvalidHttpFormat(None)}
+        }
+    }
+}
+
+impl From<DecodingChunkedErrors> for CreatingResponseErrors {
+    fn from(value: DecodingChunkedErrors) -> Self {
+        match value {
+            DecodingChunkedErrors::InsufficientDataSoReadMore => {CreatingResponseErrors::InsufficientDataSoReadMore}
+            DecodingChunkedErrors::InvalidChunkFormat => {CreatingResponseErrors::InvalidHttpFormat}
+            DecodingChunkedErrors::DangerousInvalidFormat => {CreatingResponseErrors::DangerousInvalidHttpFormat(None)}
+            DecodingChunkedErrors::MaxBodySizeReachedOut => {CreatingResponseErrors::DangerousInvalidHttpFormat(None)}
+        }
+    }
+}
+
+
+/// defining errors while resolving where the message body ends
+#[derive(Debug,PartialEq)]
+pub enum BodyFramingErrors {
+    /// when message contains both `Content-Length` and `Transfer-Encoding` ,which is a common request smuggling vector
+    ConflictingFraming,
+    /// when `Content-Length` is not a valid number or repeated with different values
+    InvalidContentLength,
+    /// when `Transfer-Encoding` is used in HTTP/1.0 ,applies chunked more than once
+    /// or request final transfer coding is not chunked
+    InvalidTransferEncoding,
+}
+
+#[allow(clippy::from_over_into)]
+impl<T> Into<Result<T,BodyFramingErrors>> for  BodyFramingErrors {
+    fn into(self) -> Result<T, BodyFramingErrors> {
+        Err(self)
+    }
+}
+
+impl From<BodyFramingErrors> for CreatingRequestErrors {
+    fn from(value: BodyFramingErrors) -> Self {
+        match value {
+            BodyFramingErrors::ConflictingFraming => {CreatingRequestErrors::DangerousInvalidHttpFormat(None)}
+            BodyFramingErrors::InvalidContentLength => {CreatingRequestErrors::InvalidHttpFormat}
+            BodyFramingErrors::InvalidTransferEncoding => {CreatingRequestErrors::InvalidHttpFormat}
+        }
+    }
+}
+
+impl From<BodyFramingErrors> for CreatingResponseErrors {
+    fn from(value: BodyFramingErrors) -> Self {
+        match value {
+            BodyFramingErrors::ConflictingFraming => {CreatingResponseErrors::DangerousInvalidHttpFormat(None)}
+            BodyFramingErrors::InvalidContentLength => {CreatingResponseErrors::InvalidHttpFormat}
+            BodyFramingErrors::InvalidTransferEncoding => {CreatingResponseErrors::InvalidHttpFormat}
+        }
+    }
+}
@@ -0,0 +1,96 @@
+use crate::body::BodyFramingErrors;
+use crate::request::headers::{bytes_to_usize, HttpHeaders};
+use crate::request::{Method, Version};
+use crate::utils::trim_ows;
+
+/// defining where the message body ends after parsing the headers (RFC 9112 section 6.3)
+#[derive(Debug,Clone,Copy,PartialEq)]
+pub enum BodyFraming {
+    /// message does not have a body
+    None,
+    /// body length is known from `Content-Length` header
+    Length(usize),
+    /// body is encoded with chunked transfer-encoding and could be decoded with [`crate::body::ChunkedDecoder`]
+    Chunked,
+    /// body continues until the server closes the connection ,which only happens in responses
+    CloseDelimited,
+}
+
+impl BodyFraming {
+
+    /// resolving request body framing from its version and headers
+    pub fn from_request<const HC:usize>(version:Version,headers:&HttpHeaders<'_,HC>)->Result<BodyFraming,BodyFramingErrors>{
+        Ok(framing_from_headers(version,headers,true)?.unwrap_or(BodyFraming::None))
+    }
+
+    /// resolving response body framing from the method of the request it answers ,its status code ,version and headers
+    pub fn from_response<const HC:usize>(request_method:Method<'_>,status_code:u16,version:Version,headers:&HttpHeaders<'_,HC>)->Result<BodyFraming,BodyFramingErrors>{
+        if request_method == Method::Head || (100..200).contains(&status_code) || status_code == 204 || status_code == 304 {
+            return Ok(BodyFraming::None)
+        }
+        // successful CONNECT response switches the connection to a tunnel right after the headers
+        if request_method == Method::Connect && (200..300).contains(&status_code) {
+            return Ok(BodyFraming::None)
+        }
+        Ok(framing_from_headers(version,headers,false)?.unwrap_or(BodyFraming::CloseDelimited))
+    }
+
+    /// checking if the message has a body that should be read
+    pub fn has_body(&self)->bool{
+        !matches!(self,BodyFraming::None | BodyFraming::Length(0))
+    }
+}
+
+fn framing_from_headers<const HC:usize>(version:Version,headers:&HttpHeaders<'_,HC>,is_request:bool)->Result<Option<BodyFraming>,BodyFramingErrors>{
+    let mut has_transfer_encoding = false;
+    let mut chunked_count = 0_usize;
+    let mut chunked_is_final = false;
+    for value in headers.get_all("transfer-encoding") {
+        for coding in value.as_bytes().split(|b| b == &b',') {
+            // transfer coding parameters are not important for framing
+            let coding = coding.split(|b| b == &b';').next().unwrap_or(coding);
+            let coding = trim_ows(coding);
+            if coding.is_empty() { continue }
+            has_transfer_encoding = true;
+            chunked_is_final = coding.eq_ignore_ascii_case(b"chunked");
+            if chunked_is_final { chunked_count += 1; }
+        }
+    }
+    let content_length = content_length(headers)?;
+    if !has_transfer_encoding {
+        return Ok(content_length.map(BodyFraming::Length))
+    }
+    // HTTP/1.0 does not know transfer-encoding so such messages are treated as faulty
+    if !version.supports_transfer_encoding() || chunked_count > 1 {
+        return BodyFramingErrors::InvalidTransferEncoding.into()
+    }
+    if content_length.is_some() {
+        return BodyFramingErrors::ConflictingFraming.into()
+    }
+    if chunked_is_final {
+        return Ok(Some(BodyFraming::Chunked))
+    }
+    if is_request {
+        return BodyFramingErrors::InvalidTransferEncoding.into()
+    }
+    Ok(Some(BodyFraming::CloseDelimited))
+}
+
+/// reading `Content-Length` from all its header lines and comma separated values
+/// which are only accepted when all of them are identical
+fn content_length<const HC:usize>(headers:&HttpHeaders<'_,HC>)->Result<Option<usize>,BodyFramingErrors>{
+    let mut content_length = None;
+    for value in headers.get_all("content-length") {
+        for element in value.as_bytes().split(|b| b == &b',') {
+            let length = match bytes_to_usize(trim_ows(element)) {
+                None => {return BodyFramingErrors::InvalidContentLength.into()}
+                Some(l) => {l}
+            };
+            match content_length {
+                Some(previous) if previous != length => {return BodyFramingErrors::InvalidContentLength.into()}
+                _ => {content_length = Some(length)}
+            }
+        }
+    }
+    Ok(content_length)
+}
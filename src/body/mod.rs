@@ -0,0 +1,9 @@
+mod errors;
+mod chunked;
+mod framing;
+
+pub use errors::*;
+/// using chunked transfer-encoding implementations
+pub use chunked::*;
+/// using body framing resolution
+pub use framing::*;
@@ -5,16 +5,24 @@ static mut CONFIG:HttpHConfigurations  = HttpHConfigurations {
      max_version_size:20,
      max_path_size:1024 * 3,
      max_headers_size:1024 * 10,
-     max_body_size:None
+     max_body_size:None,
+     strict_mode:false,
+     headers_overflow_policy:HeadersOverflowPolicy::Error,
+     max_headers_count:100,
+     allow_obsolete_line_folding:false,
+     allow_http09:false,
 };
 
-
 pub (crate) fn global_config()->&'static HttpHConfigurations {
     unsafe{addr_of!(CONFIG).as_ref().unwrap()}
 }
 
 /// setting new Http Configurations for http operations like forming new http request
 /// note: this method is not safe for use while running the server ,so you should call this function in the main fn ( the entry point )
+///
+/// # Safety
+/// configurations are stored in a global static without any synchronization,
+/// so the caller must make sure no other thread is reading or parsing http messages while calling it
 pub unsafe fn set_new_http_configurations(conf:HttpHConfigurations){
     CONFIG = conf
 }
@@ -32,20 +40,58 @@ pub struct HttpHConfigurations {
     /// defining max headers size without including the first line because we already prepared configs for that
     pub max_headers_size:usize,
     /// max incoming request body size
-    pub max_body_size:Option<usize>
+    pub max_body_size:Option<usize>,
+    /// rejecting ambiguous messages which could be used for request smuggling like duplicate `Content-Length`
+    /// or `Content-Length` with `Transfer-Encoding`
+    /// ,and requests without exactly one valid `Host` header matching absolute-form request-target
+    ///
+    /// white spaces before header colon and folded `Content-Length` or `Transfer-Encoding` values
+    /// are rejected in both modes
+    pub strict_mode:bool,
+    /// what to do with header lines beyond the headers count capacity
+    pub headers_overflow_policy:HeadersOverflowPolicy,
+    /// max header lines count when [`HeadersOverflowPolicy::SpillToHeap`] is used
+    /// ,the inline headers capacity is always accepted even if it is bigger
+    pub max_headers_count:usize,
+    /// accepting header values continued on lines starting with white space (RFC 9112 section 5.2)
+    /// ,which are rejected by default ,use [`crate::request::headers::HeaderValue::to_unfolded_str`] to read such values
+    /// ,while folded `Content-Length` and `Transfer-Encoding` values are always rejected
+    pub allow_obsolete_line_folding:bool,
+    /// accepting HTTP/0.9 simple requests like `GET /path` without version and headers
+    /// ,which are rejected by default because other servers in the chain could read them differently
+    pub allow_http09:bool,
+}
+
+/// defining what happens when message has more header lines than the headers count capacity
+#[derive(Debug,Clone,Copy,PartialEq,Default)]
+pub enum HeadersOverflowPolicy {
+    /// rejecting the message with [`crate::request::headers::CreatingHeadersErrors::TooManyHeaders`]
+    #[default]
+    Error,
+    /// ignoring the extra lines while counting them in [`crate::request::headers::HttpHeaders::dropped_count`]
+    /// ,except `Content-Length` ,`Transfer-Encoding` and `Host` lines which are rejected like [`HeadersOverflowPolicy::Error`]
+    /// because the message could not be framed or routed without them
+    Drop,
+    /// keeping the extra lines in heap allocated storage up to [`HttpHConfigurations::max_headers_count`]
+    SpillToHeap,
 }
 
 
 
-impl HttpHConfigurations {
+impl Default for HttpHConfigurations {
 
-    pub fn default()->Self{
+    fn default()->Self{
         Self {
             max_method_size:10,
             max_version_size:25,
             max_path_size:1024 * 15,
             max_headers_size:1024 * 10,
-            max_body_size:None
+            max_body_size:None,
+            strict_mode:false,
+            headers_overflow_policy:HeadersOverflowPolicy::Error,
+            max_headers_count:100,
+            allow_obsolete_line_folding:false,
+            allow_http09:false,
         }
     }
 }
\ No newline at end of file
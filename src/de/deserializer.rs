@@ -0,0 +1,276 @@
+use std::borrow::Cow;
+use serde::de::{DeserializeSeed, IntoDeserializer, MapAccess, SeqAccess, Visitor};
+use serde::Deserializer;
+use crate::de::{join_key, DeserializingErrors};
+use crate::de::value::Node;
+
+/// part of the tree which is being deserialized
+#[derive(Clone,Copy)]
+enum Source<'a,'de> {
+    Node(&'a Node<'de>),
+    /// one value out of repeated values
+    Scalar(Option<&'a Cow<'de,str>>),
+}
+
+/// deserializing one key of the tree ,and remembering its full path for error messages
+pub (crate) struct ValueDeserializer<'a,'de> {
+    source:Source<'a,'de>,
+    key:String,
+}
+
+impl<'a,'de> ValueDeserializer<'a,'de> {
+
+    pub (crate) fn root(node:&'a Node<'de>)->ValueDeserializer<'a,'de>{
+        ValueDeserializer {
+            source:Source::Node(node),
+            key:String::new(),
+        }
+    }
+
+    fn invalid<T>(&self,reason:impl Into<String>)->Result<T,DeserializingErrors>{
+        DeserializingErrors::InvalidValue {key:self.key.clone(),reason:reason.into()}.into()
+    }
+
+    /// getting the only value of the key ,where [`None`] means valueless flag
+    fn single(&self)->Result<Option<&'a Cow<'de,str>>,DeserializingErrors>{
+        match self.source {
+            Source::Scalar(value) => {Ok(value)}
+            Source::Node(Node::Values(values)) => {
+                match values.as_slice() {
+                    [value] => {Ok(value.as_ref())}
+                    _ => {self.invalid(format!("expected single value but found {}",values.len()))}
+                }
+            }
+            Source::Node(Node::Map(_)) => {self.invalid("expected value but found nested keys")}
+            Source::Node(Node::Invalid(reason)) => {self.invalid(reason.as_str())}
+        }
+    }
+
+    fn visit_str<V:Visitor<'de>>(&self,visitor:V)->Result<V::Value,DeserializingErrors>{
+        let result = match self.single()? {
+            None => {visitor.visit_borrowed_str("")}
+            Some(Cow::Borrowed(value)) => {visitor.visit_borrowed_str(value)}
+            Some(Cow::Owned(value)) => {visitor.visit_str(value)}
+        };
+        result.map_err(|e:DeserializingErrors| e.at(&self.key))
+    }
+
+    fn parse<T:std::str::FromStr>(&self)->Result<T,DeserializingErrors> where T::Err:std::fmt::Display {
+        match self.single()? {
+            None => {self.invalid("expected value but found valueless key")}
+            Some(value) => {
+                match value.parse() {
+                    Ok(v) => {Ok(v)}
+                    Err(e) => {self.invalid(format!("{e} (`{value}`)"))}
+                }
+            }
+        }
+    }
+}
+
+macro_rules! deserialize_parsed {
+    ($($method:ident => $visit:ident),*) => {
+        $(
+            fn $method<V:Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+                let value = self.parse()?;
+                visitor.$visit(value).map_err(|e:DeserializingErrors| e.at(&self.key))
+            }
+        )*
+    };
+}
+
+impl<'de> Deserializer<'de> for ValueDeserializer<'_,'de> {
+    type Error = DeserializingErrors;
+
+    fn deserialize_any<V:Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        match self.source {
+            Source::Node(Node::Values(values)) if values.len() > 1 => {self.deserialize_seq(visitor)}
+            Source::Node(Node::Map(_)) => {self.deserialize_map(visitor)}
+            _ => {self.visit_str(visitor)}
+        }
+    }
+
+    deserialize_parsed!(
+        deserialize_i8 => visit_i8, deserialize_i16 => visit_i16, deserialize_i32 => visit_i32,
+        deserialize_i64 => visit_i64, deserialize_i128 => visit_i128,
+        deserialize_u8 => visit_u8, deserialize_u16 => visit_u16, deserialize_u32 => visit_u32,
+        deserialize_u64 => visit_u64, deserialize_u128 => visit_u128,
+        deserialize_f32 => visit_f32, deserialize_f64 => visit_f64, deserialize_char => visit_char
+    );
+
+    fn deserialize_bool<V:Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        let value = match self.single()?.map(|v| v.as_ref()) {
+            // valueless flag like `?debug` means true
+            None | Some("true") | Some("1") | Some("on") | Some("yes") => {true}
+            Some("false") | Some("0") | Some("off") | Some("no") => {false}
+            Some(value) => {return self.invalid(format!("expected boolean but found `{value}`"))}
+        };
+        visitor.visit_bool(value).map_err(|e:DeserializingErrors| e.at(&self.key))
+    }
+
+    fn deserialize_str<V:Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        self.visit_str(visitor)
+    }
+
+    fn deserialize_string<V:Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        self.visit_str(visitor)
+    }
+
+    fn deserialize_bytes<V:Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        self.visit_str(visitor)
+    }
+
+    fn deserialize_byte_buf<V:Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        self.visit_str(visitor)
+    }
+
+    fn deserialize_option<V:Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        // empty value like `page=` is treated as missing value
+        if let Ok(Some(value)) = self.single() {
+            if value.is_empty() { return visitor.visit_none() }
+        }
+        visitor.visit_some(self)
+    }
+
+    fn deserialize_unit<V:Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        visitor.visit_unit()
+    }
+
+    fn deserialize_unit_struct<V:Visitor<'de>>(self, _name: &'static str, visitor: V) -> Result<V::Value, Self::Error> {
+        visitor.visit_unit()
+    }
+
+    fn deserialize_newtype_struct<V:Visitor<'de>>(self, _name: &'static str, visitor: V) -> Result<V::Value, Self::Error> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_seq<V:Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        let mut access = ValuesAccess {
+            values:match self.source {
+                Source::Node(Node::Values(values)) => {ValuesIter::Values(values.iter())}
+                // `a[0]=x&a[1]=y` is deserialized in order of appearance
+                Source::Node(Node::Map(entries)) => {ValuesIter::Entries(entries.iter())}
+                Source::Scalar(value) => {ValuesIter::Scalar(Some(value))}
+                Source::Node(Node::Invalid(reason)) => {return self.invalid(reason.as_str())}
+            },
+            key:&self.key,
+            child_failed:false,
+        };
+        let result = visitor.visit_seq(&mut access);
+        match result {
+            Err(e) if !access.child_failed => {Err(e.at(&self.key))}
+            result => {result}
+        }
+    }
+
+    fn deserialize_tuple<V:Visitor<'de>>(self, _len: usize, visitor: V) -> Result<V::Value, Self::Error> {
+        self.deserialize_seq(visitor)
+    }
+
+    fn deserialize_tuple_struct<V:Visitor<'de>>(self, _name: &'static str, _len: usize, visitor: V) -> Result<V::Value, Self::Error> {
+        self.deserialize_seq(visitor)
+    }
+
+    fn deserialize_map<V:Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        let entries = match self.source {
+            Source::Node(Node::Map(entries)) => {entries}
+            Source::Node(Node::Invalid(reason)) => {return self.invalid(reason.as_str())}
+            _ => {return self.invalid("expected nested keys but found value")}
+        };
+        let mut access = EntriesAccess {
+            entries:entries.iter(),
+            value:None,
+            key:&self.key,
+            child_failed:false,
+        };
+        let result = visitor.visit_map(&mut access);
+        match result {
+            Err(e) if !access.child_failed => {Err(e.at(&self.key))}
+            result => {result}
+        }
+    }
+
+    fn deserialize_struct<V:Visitor<'de>>(self, _name: &'static str, _fields: &'static [&'static str], visitor: V) -> Result<V::Value, Self::Error> {
+        self.deserialize_map(visitor)
+    }
+
+    fn deserialize_enum<V:Visitor<'de>>(self, _name: &'static str, _variants: &'static [&'static str], visitor: V) -> Result<V::Value, Self::Error> {
+        // only unit variants could be written as plain values
+        let value = self.single()?.map(|v| v.as_ref()).unwrap_or_default();
+        let deserializer:serde::de::value::StrDeserializer<DeserializingErrors> = value.into_deserializer();
+        visitor.visit_enum(deserializer).map_err(|e:DeserializingErrors| e.at(&self.key))
+    }
+
+    fn deserialize_identifier<V:Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        self.visit_str(visitor)
+    }
+
+    fn deserialize_ignored_any<V:Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        visitor.visit_unit()
+    }
+}
+
+enum ValuesIter<'a,'de> {
+    Values(std::slice::Iter<'a,Option<Cow<'de,str>>>),
+    Entries(std::slice::Iter<'a,(Cow<'de,str>,Node<'de>)>),
+    Scalar(Option<Option<&'a Cow<'de,str>>>),
+}
+
+/// accessing repeated values or nested entries as sequence
+struct ValuesAccess<'a,'de,'k> {
+    values:ValuesIter<'a,'de>,
+    key:&'k str,
+    child_failed:bool,
+}
+
+impl<'de> SeqAccess<'de> for ValuesAccess<'_,'de,'_> {
+    type Error = DeserializingErrors;
+
+    fn next_element_seed<T:DeserializeSeed<'de>>(&mut self, seed: T) -> Result<Option<T::Value>, Self::Error> {
+        let (source,key) = match &mut self.values {
+            ValuesIter::Values(values) => {
+                let Some(value) = values.next() else { return Ok(None) };
+                (Source::Scalar(value.as_ref()),self.key.to_string())
+            }
+            ValuesIter::Entries(entries) => {
+                let Some((k,node)) = entries.next() else { return Ok(None) };
+                (Source::Node(node),join_key(self.key,k))
+            }
+            ValuesIter::Scalar(value) => {
+                let Some(value) = value.take() else { return Ok(None) };
+                (Source::Scalar(value),self.key.to_string())
+            }
+        };
+        let result = seed.deserialize(ValueDeserializer {source,key});
+        self.child_failed = result.is_err();
+        result.map(Some)
+    }
+}
+
+/// accessing nested entries as map or struct fields
+struct EntriesAccess<'a,'de,'k> {
+    entries:std::slice::Iter<'a,(Cow<'de,str>,Node<'de>)>,
+    value:Option<&'a (Cow<'de,str>,Node<'de>)>,
+    key:&'k str,
+    child_failed:bool,
+}
+
+impl<'de> MapAccess<'de> for EntriesAccess<'_,'de,'_> {
+    type Error = DeserializingErrors;
+
+    fn next_key_seed<K:DeserializeSeed<'de>>(&mut self, seed: K) -> Result<Option<K::Value>, Self::Error> {
+        let Some(entry) = self.entries.next() else { return Ok(None) };
+        self.value = Some(entry);
+        let key = ValueDeserializer {source:Source::Scalar(Some(&entry.0)),key:join_key(self.key,&entry.0)};
+        seed.deserialize(key).map(Some)
+    }
+
+    fn next_value_seed<V:DeserializeSeed<'de>>(&mut self, seed: V) -> Result<V::Value, Self::Error> {
+        let Some((k,node)) = self.value.take() else {
+            return DeserializingErrors::InvalidValue {key:self.key.to_string(),reason:"value requested before key".to_string()}.into()
+        };
+        let result = seed.deserialize(ValueDeserializer {source:Source::Node(node),key:join_key(self.key,k)});
+        self.child_failed = result.is_err();
+        result
+    }
+}
@@ -0,0 +1,80 @@
+use std::fmt::{Display, Formatter};
+
+/// defining errors while deserializing request data into rust types
+///
+/// every variant points at the failing key ,where nested keys are written like `user[name]`
+#[derive(Debug,Clone,PartialEq)]
+pub enum DeserializingErrors {
+    /// when input could not be split into keys and values
+    /// ,like invalid percent-encoding or a key used both as value and as nested keys
+    InvalidFormat {
+        /// failing key
+        key:String,
+        /// why the input is invalid
+        reason:String,
+    },
+    /// when required field does not exist in the input
+    MissingField {
+        /// missing key
+        key:String,
+    },
+    /// when value exists but could not be converted to the requested type
+    InvalidValue {
+        /// failing key ,which is empty for top level errors
+        key:String,
+        /// why the value is invalid
+        reason:String,
+    },
+}
+
+impl DeserializingErrors {
+
+    /// attaching key path to errors which were raised without knowing their key
+    pub (crate) fn at(self,path:&str)->DeserializingErrors{
+        match self {
+            DeserializingErrors::InvalidValue {key,reason} if key.is_empty() => {
+                DeserializingErrors::InvalidValue {key:path.to_string(),reason}
+            }
+            DeserializingErrors::MissingField {key} => {
+                DeserializingErrors::MissingField {key:join_key(path,&key)}
+            }
+            e => {e}
+        }
+    }
+}
+
+/// joining nested key like `user` and `name` into `user[name]`
+pub (crate) fn join_key(path:&str,key:&str)->String{
+    if path.is_empty() { return key.to_string() }
+    format!("{path}[{key}]")
+}
+
+impl Display for DeserializingErrors {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DeserializingErrors::InvalidFormat {key,reason} => {write!(f,"invalid format at key `{key}`: {reason}")}
+            DeserializingErrors::MissingField {key} => {write!(f,"missing field `{key}`")}
+            DeserializingErrors::InvalidValue {key,reason} if key.is_empty() => {write!(f,"invalid value: {reason}")}
+            DeserializingErrors::InvalidValue {key,reason} => {write!(f,"invalid value for key `{key}`: {reason}")}
+        }
+    }
+}
+
+impl std::error::Error for DeserializingErrors {}
+
+impl serde::de::Error for DeserializingErrors {
+    fn custom<T: Display>(msg: T) -> Self {
+        DeserializingErrors::InvalidValue {key:String::new(),reason:msg.to_string()}
+    }
+
+    fn missing_field(field: &'static str) -> Self {
+        DeserializingErrors::MissingField {key:field.to_string()}
+    }
+}
+
+#[allow(clippy::from_over_into)]
+impl<T> Into<Result<T,DeserializingErrors>> for  DeserializingErrors {
+    fn into(self) -> Result<T, DeserializingErrors> {
+        Err(self)
+    }
+}
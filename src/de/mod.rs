@@ -0,0 +1,179 @@
+mod errors;
+mod value;
+mod deserializer;
+
+pub use errors::*;
+use std::borrow::Cow;
+use serde::Deserialize;
+use crate::request::headers::HttpHeaders;
+use crate::de::deserializer::ValueDeserializer;
+use crate::de::value::Node;
+
+/// deserializing query string without the leading `?` into typed struct
+///
+/// keys and values are percent-decoded ,repeated keys fill [`Vec`] fields
+/// ,valueless flags are `true` for booleans and nested keys like `user[name]=x` fill nested structs
+///
+/// # Example
+/// ```
+/// use serde::Deserialize;
+/// #[derive(Deserialize)]
+/// struct Search {
+///     q:String,
+///     page:Option<u32>,
+///     tag:Vec<String>,
+///     debug:bool,
+/// }
+/// let search:Search = water_http_utils::de::from_query(b"q=rust+http&tag=a&tag=b&debug").unwrap();
+/// assert_eq!(search.q,"rust http");
+/// assert_eq!(search.page,None);
+/// assert_eq!(search.tag,vec!["a","b"]);
+/// assert!(search.debug);
+/// ```
+pub fn from_query<'de,T:Deserialize<'de>>(query:&'de [u8])->Result<T,DeserializingErrors>{
+    let node = Node::from_query(query)?;
+    T::deserialize(ValueDeserializer::root(&node))
+}
+
+/// deserializing any list of name and value pairs ,like router parameters ,into typed struct
+///
+/// values are used as they are without percent-decoding ,and repeated names fill [`Vec`] fields
+/// ,names could be borrowed `&str` or owned [`String`] and borrowed names are never copied
+///
+/// # Example
+/// ```
+/// use serde::Deserialize;
+/// #[derive(Deserialize)]
+/// struct Params {
+///     id:u64,
+///     slug:String,
+/// }
+/// let params:Params = water_http_utils::de::from_pairs([("id","42"),("slug","hello")]).unwrap();
+/// assert_eq!(params.id,42);
+/// ```
+pub fn from_pairs<'de,T:Deserialize<'de>,K:Into<Cow<'de,str>>>(pairs:impl IntoIterator<Item = (K,&'de str)>)->Result<T,DeserializingErrors>{
+    let node = Node::from_pairs(pairs.into_iter().map(|(k,v)| (k.into(),Ok(Cow::Borrowed(v)))));
+    T::deserialize(ValueDeserializer::root(&node))
+}
+
+/// deserializing header lines into typed struct ,where header names are matched in lower case
+/// ,so fields should be renamed like `#[serde(rename = "x-request-id")]`
+///
+/// header values which are not valid utf-8 fail only when their field is requested
+/// ,and values with obsolete line folding are unfolded
+pub fn from_headers<'de,T:Deserialize<'de>,const HC:usize>(headers:&HttpHeaders<'de,HC>)->Result<T,DeserializingErrors>{
+    let node = Node::from_pairs(headers.lines().into_iter().map(|line| {
+        let key = if line.key.bytes().any(|b| b.is_ascii_uppercase()) {
+            Cow::Owned(line.key.to_ascii_lowercase())
+        } else {
+            Cow::Borrowed(line.key)
+        };
+        let value = line.value.to_unfolded_str().map_err(|_| "header value is not valid utf-8".to_string());
+        (key,value)
+    }));
+    T::deserialize(ValueDeserializer::root(&node))
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashMap;
+    use serde::Deserialize;
+    use crate::de::{from_pairs, from_query, DeserializingErrors};
+
+    #[derive(Debug, Deserialize, PartialEq)]
+    struct Filter {
+        min: f64,
+        max: Option<f64>,
+    }
+
+    #[derive(Debug, Deserialize, PartialEq)]
+    #[serde(rename_all = "lowercase")]
+    enum Order {
+        Asc,
+        Desc,
+    }
+
+    #[derive(Debug, Deserialize, PartialEq)]
+    struct Search<'a> {
+        q: String,
+        lang: &'a str,
+        page: u32,
+        limit: Option<u8>,
+        tag: Vec<String>,
+        debug: bool,
+        order: Order,
+        filter: Filter,
+    }
+
+    #[test]
+    fn test_typed_struct() {
+        let search: Search = from_query(b"q=rust+http%20server&lang=en&page=2&limit=&tag=a&tag=b&debug&order=desc&filter[min]=1.5").unwrap();
+        assert_eq!(search, Search {
+            q: "rust http server".to_string(),
+            lang: "en",
+            page: 2,
+            limit: None,
+            tag: vec!["a".to_string(), "b".to_string()],
+            debug: true,
+            order: Order::Desc,
+            filter: Filter { min: 1.5, max: None },
+        });
+    }
+
+    #[test]
+    fn test_sequences_and_maps() {
+        let ids: HashMap<String, Vec<u64>> = from_query(b"ids[]=1&ids[]=2&ids[]=3").unwrap();
+        assert_eq!(ids["ids"], vec![1, 2, 3]);
+        let single: HashMap<String, Vec<u64>> = from_query(b"ids=7").unwrap();
+        assert_eq!(single["ids"], vec![7]);
+        let nested: HashMap<String, HashMap<String, String>> = from_query(b"a[b]=c&a[d]=e").unwrap();
+        assert_eq!(nested["a"]["d"], "e");
+    }
+
+    #[test]
+    fn test_pairs() {
+        #[derive(Debug, Deserialize)]
+        struct Params<'a> {
+            id: u64,
+            slug: &'a str,
+            tag: Vec<String>,
+            draft: Option<bool>,
+        }
+        let params: Params = from_pairs([("id", "42"), ("slug", "a%20b"), ("tag", "x"), ("tag", "y")]).unwrap();
+        assert_eq!((params.id, params.slug, params.tag.len(), params.draft), (42, "a%20b", 2, None));
+        let error = from_pairs::<Params, _>([("id", "x"), ("slug", "a")]).unwrap_err();
+        assert_eq!(error.to_string(), "invalid value for key `id`: invalid digit found in string (`x`)");
+        let error = from_pairs::<Params, _>([("id", "1")]).unwrap_err();
+        assert_eq!(error.to_string(), "missing field `slug`");
+        // borrowed names are not copied ,so they could be deserialized as borrowed keys
+        let borrowed: HashMap<&str, &str> = from_pairs([("id", "1"), ("slug", "a")]).unwrap();
+        assert_eq!(borrowed["slug"], "a");
+        let owned: HashMap<String, u64> = from_pairs([("id".to_string(), "1")]).unwrap();
+        assert_eq!(owned["id"], 1);
+    }
+
+    #[test]
+    fn test_errors_point_at_key() {
+        #[derive(Debug, Deserialize)]
+        #[allow(dead_code)]
+        struct Params {
+            page: u32,
+            filter: Filter,
+        }
+        let error = from_query::<Params>(b"page=two&filter[min]=1").unwrap_err();
+        assert!(matches!(&error, DeserializingErrors::InvalidValue { key, .. } if key == "page"));
+        assert!(error.to_string().contains("`page`"));
+        let error = from_query::<Params>(b"page=1&filter[min]=x").unwrap_err();
+        assert!(matches!(&error, DeserializingErrors::InvalidValue { key, .. } if key == "filter[min]"));
+        let error = from_query::<Params>(b"page=1&filter[max]=2").unwrap_err();
+        assert_eq!(error, DeserializingErrors::MissingField { key: "filter[min]".to_string() });
+        let error = from_query::<Params>(b"filter[min]=1").unwrap_err();
+        assert_eq!(error, DeserializingErrors::MissingField { key: "page".to_string() });
+        let error = from_query::<Params>(b"page=1&page=2&filter[min]=1").unwrap_err();
+        assert!(matches!(&error, DeserializingErrors::InvalidValue { key, .. } if key == "page"));
+        let error = from_query::<Params>(b"filter=1&filter[min]=1").unwrap_err();
+        assert!(matches!(&error, DeserializingErrors::InvalidFormat { key, .. } if key == "filter[min]"));
+        let error = from_query::<Params>(b"page=%zz").unwrap_err();
+        assert!(matches!(&error, DeserializingErrors::InvalidFormat { key, .. } if key == "page"));
+    }
+}
@@ -0,0 +1,132 @@
+use std::borrow::Cow;
+use crate::de::DeserializingErrors;
+use crate::request::percent_decode_query;
+
+/// intermediate tree of keys and values which deserializers walk through
+///
+/// repeated keys are collected in [`Node::Values`] and nested keys like `a[b]=c` create [`Node::Map`]
+#[derive(Debug)]
+pub (crate) enum Node<'de> {
+    /// values of a key in order ,where [`None`] means a valueless flag like `?debug`
+    Values(Vec<Option<Cow<'de,str>>>),
+    /// nested keys in their first appearance order
+    Map(Vec<(Cow<'de,str>,Node<'de>)>),
+    /// value which could not be converted to string ,with the reason
+    /// ,so the error is raised only when the key is requested
+    Invalid(String),
+}
+
+impl<'de> Node<'de> {
+
+    pub (crate) fn new_map()->Node<'de>{
+        Node::Map(Vec::new())
+    }
+
+    /// building tree from query string like `tag=a&tag=b&user[name]=x` with percent-decoding
+    pub (crate) fn from_query(query:&'de [u8])->Result<Node<'de>,DeserializingErrors>{
+        let mut root = Node::new_map();
+        for pair in query.split(|b| b == &b'&') {
+            if pair.is_empty() { continue }
+            let (key,value) = match pair.iter().position(|b| b == &b'=') {
+                None => {(pair,None)}
+                Some(index) => {(&pair[..index],Some(&pair[index + 1..]))}
+            };
+            let key = decode(key,key)?;
+            let value = match value {
+                None => {None}
+                Some(value) => {Some(decode(value,key.as_bytes())?)}
+            };
+            root.insert(key,value)?;
+        }
+        Ok(root)
+    }
+
+    /// building flat tree from name and value pairs without decoding
+    pub (crate) fn from_pairs(pairs:impl IntoIterator<Item = (Cow<'de,str>,Result<Cow<'de,str>,String>)>)->Node<'de>{
+        let mut entries:Vec<(Cow<'de,str>,Node<'de>)> = Vec::new();
+        for (key,value) in pairs {
+            let position = match entries.iter().position(|(k,_)| k == &key) {
+                Some(position) => {position}
+                None => {
+                    entries.push((key,Node::Values(Vec::new())));
+                    entries.len() - 1
+                }
+            };
+            let node = &mut entries[position].1;
+            match (value,&mut *node) {
+                (Ok(value),Node::Values(values)) => {values.push(Some(value))}
+                (Err(reason),_) => {*node = Node::Invalid(reason)}
+                _ => {}
+            }
+        }
+        Node::Map(entries)
+    }
+
+    /// inserting value of key which could contain nested `[segment]` parts
+    pub (crate) fn insert(&mut self,key:Cow<'de,str>,value:Option<Cow<'de,str>>)->Result<(),DeserializingErrors>{
+        let segments = split_key(&key);
+        let mut node = self;
+        let mut path = String::new();
+        for (index,segment) in segments.iter().enumerate() {
+            let is_last = index + 1 == segments.len();
+            // `a[]=1&a[]=2` appends to the values of `a`
+            if is_last && segment.is_empty() && index > 0 { break }
+            path = crate::de::join_key(&path,segment);
+            let Node::Map(entries) = node else {
+                return conflicting(path)
+            };
+            let position = match entries.iter().position(|(k,_)| k == segment) {
+                Some(position) => {position}
+                None => {
+                    let is_value = is_last || (index + 2 == segments.len() && segments[index + 1].is_empty());
+                    let child = if is_value {Node::Values(Vec::new())} else {Node::new_map()};
+                    entries.push((sub_key(&key,segment),child));
+                    entries.len() - 1
+                }
+            };
+            node = &mut entries[position].1;
+        }
+        match node {
+            Node::Values(values) => {
+                values.push(value);
+                Ok(())
+            }
+            _ => {conflicting(path)}
+        }
+    }
+}
+
+fn conflicting<T>(key:String)->Result<T,DeserializingErrors>{
+    DeserializingErrors::InvalidFormat {key,reason:"key is used both as value and as nested keys".to_string()}.into()
+}
+
+fn decode<'de>(bytes:&'de [u8],key:&[u8])->Result<Cow<'de,str>,DeserializingErrors>{
+    percent_decode_query(bytes).map_err(|e| DeserializingErrors::InvalidFormat {
+        key:String::from_utf8_lossy(key).into_owned(),
+        reason:format!("{e:?}")
+    })
+}
+
+/// splitting `a[b][]` into `a` ,`b` and empty segment
+/// ,keys with unbalanced brackets are kept as one segment
+fn split_key(key:&str)->Vec<&str>{
+    let Some(open) = key.find('[') else { return vec![key] };
+    if open == 0 || !key.ends_with(']') { return vec![key] }
+    let mut segments = vec![&key[..open]];
+    for part in key[open + 1..key.len() - 1].split("][") {
+        if part.contains(['[',']']) { return vec![key] }
+        segments.push(part);
+    }
+    segments
+}
+
+/// borrowing segment from key when key itself is borrowed
+fn sub_key<'de>(key:&Cow<'de,str>,segment:&str)->Cow<'de,str>{
+    match key {
+        Cow::Borrowed(key) => {
+            let start = segment.as_ptr() as usize - key.as_ptr() as usize;
+            Cow::Borrowed(&key[start..start + segment.len()])
+        }
+        Cow::Owned(_) => {Cow::Owned(segment.to_string())}
+    }
+}
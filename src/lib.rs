@@ -1,7 +1,19 @@
 
 /// contains all request functionalities
 pub mod request;
+/// contains all response functionalities
+pub mod response;
+/// http message body functionalities like chunked transfer-encoding
+pub mod body;
 
 mod utils;
+/// writing http messages into caller buffers
+pub mod writer;
 /// defining important http configurations
 pub mod config;
+/// serde deserializers over request data like query strings ,headers and path parameters
+pub mod de;
+/// matching request paths against route templates
+pub mod router;
+/// parsing uris and authorities
+pub mod uri;
@@ -0,0 +1,131 @@
+use crate::utils::is_token_byte;
+use crate::request::Version;
+use crate::writer::{MessageParts, WriteHttpMessage, WritingHttpErrors};
+
+/// building outbound http requests and serializing them into caller buffers without intermediate allocations
+///
+/// # Example
+/// ```
+/// use water_http_utils::request::HttpRequestBuilder;
+/// use water_http_utils::writer::WriteHttpMessage;
+/// let request = HttpRequestBuilder::<8>::new("POST","/submit").unwrap()
+///     .header("Host","example.com").unwrap()
+///     .body(b"name=Hassan");
+/// let mut bytes = vec![];
+/// request.write_into_vec(&mut bytes);
+/// assert_eq!(bytes,b"POST /submit HTTP/1.1\r\nHost: example.com\r\nContent-Length: 11\r\n\r\nname=Hassan");
+/// ```
+#[derive(Debug)]
+pub struct HttpRequestBuilder<'a,const HC:usize> {
+    method:&'a str,
+    target:&'a str,
+    version:Version,
+    parts:MessageParts<'a,HC>,
+}
+
+impl<'a,const HC:usize> HttpRequestBuilder<'a,HC> {
+
+    /// creating new request builder with `HTTP/1.1` version
+    pub fn new(method:&'a str,target:&'a str)->Result<HttpRequestBuilder<'a,HC>,WritingHttpErrors>{
+        if method.is_empty() || !method.bytes().all(is_token_byte) { return WritingHttpErrors::InvalidMethod.into() }
+        if target.is_empty() || !target.bytes().all(|b| (0x21..=0x7e).contains(&b)) {
+            return WritingHttpErrors::InvalidRequestTarget.into()
+        }
+        Ok(
+            HttpRequestBuilder {
+                method,
+                target,
+                version:Version::Http11,
+                parts:MessageParts::new(),
+            }
+        )
+    }
+
+    /// changing request version ,HTTP/0.9 is not supported because it does not have headers
+    pub fn version(mut self,version:Version)->Result<Self,WritingHttpErrors>{
+        if !version.supports_headers() { return WritingHttpErrors::InvalidVersion.into() }
+        self.version = version;
+        Ok(self)
+    }
+
+    /// adding new header line
+    pub fn header(self,key:&'a str,value:&'a str)->Result<Self,WritingHttpErrors>{
+        self.header_bytes(key,value.as_bytes())
+    }
+
+    /// adding new header line with raw value bytes
+    pub fn header_bytes(mut self,key:&'a str,value:&'a [u8])->Result<Self,WritingHttpErrors>{
+        self.parts.push_header(key,value)?;
+        Ok(self)
+    }
+
+    /// setting request body
+    ///
+    /// `Content-Length` header is added automatically unless `Content-Length` or `Transfer-Encoding`
+    /// headers were added manually
+    pub fn body(mut self,body:&'a [u8])->Self{
+        self.parts.set_body(body);
+        self
+    }
+}
+
+impl<const HC:usize> WriteHttpMessage for HttpRequestBuilder<'_,HC> {
+    fn for_each_part<'s,E>(&'s self,f:&mut impl FnMut(&'s [u8])->Result<(),E>)->Result<(),E>{
+        f(self.method.as_bytes())?;
+        f(b" ")?;
+        f(self.target.as_bytes())?;
+        f(b" ")?;
+        f(self.version.as_str().as_bytes())?;
+        f(b"\r\n")?;
+        self.parts.for_each_part(true,f)
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use crate::request::{HttpRequestBuilder, Version};
+    use crate::writer::{WriteHttpMessage, WritingHttpErrors};
+
+    #[test]
+    fn test_write_into_slice() {
+        let request = HttpRequestBuilder::<4>::new("GET","/search?q=rust").unwrap()
+            .header("Host","example.com").unwrap()
+            .header("Accept","*/*").unwrap();
+        let mut buf = [0_u8;128];
+        let written = request.write_into_slice(&mut buf).unwrap();
+        assert_eq!(&buf[..written], b"GET /search?q=rust HTTP/1.1\r\nHost: example.com\r\nAccept: */*\r\n\r\n");
+        assert_eq!(written, request.written_length());
+    }
+
+    #[test]
+    fn test_rejecting_invalid_parts() {
+        assert_eq!(HttpRequestBuilder::<4>::new("GE T","/").err(), Some(WritingHttpErrors::InvalidMethod));
+        assert_eq!(HttpRequestBuilder::<4>::new("GET","/a b").err(), Some(WritingHttpErrors::InvalidRequestTarget));
+        assert_eq!(HttpRequestBuilder::<4>::new("GET","").err(), Some(WritingHttpErrors::InvalidRequestTarget));
+        let builder = HttpRequestBuilder::<4>::new("GET","/").unwrap();
+        assert_eq!(builder.header("Host","a\nb").err(), Some(WritingHttpErrors::InvalidHeaderValue));
+        let builder = HttpRequestBuilder::<4>::new("GET","/").unwrap();
+        assert_eq!(builder.version(Version::Http09).err(), Some(WritingHttpErrors::InvalidVersion));
+    }
+
+    #[cfg(feature = "server")]
+    #[test]
+    fn test_round_trip_through_request_parser() {
+        use crate::request::HttpRequest;
+        let request = HttpRequestBuilder::<4>::new("PUT","/user/123").unwrap()
+            .header("Host","example.com").unwrap()
+            .header("Content-Type","application/json").unwrap()
+            .body(b"{\"age\":30}");
+        let mut bytes = vec![];
+        request.write_into_vec(&mut bytes);
+        let parsed = HttpRequest::<4>::from_bytes(&bytes).unwrap();
+        assert_eq!(parsed.method(), "PUT");
+        assert_eq!(parsed.path().to_str(), "/user/123");
+        assert_eq!(parsed.version(), "HTTP/1.1");
+        assert_eq!(parsed.headers().get_as_str("Host"), Some("example.com"));
+        assert_eq!(parsed.headers().content_length, Some(10));
+        let head_length = parsed.first_line().first_line_length + parsed.headers().headers_length;
+        assert_eq!(&bytes[head_length..], b"{\"age\":30}");
+    }
+}
@@ -1,6 +1,6 @@
 
 
-#[derive(Debug)]
+#[derive(Debug,Clone,Copy,PartialEq)]
 pub (crate) enum CreatingRequestSteps {
     FirstLine,
     Headers,
@@ -1,6 +1,12 @@
+#[cfg(feature = "server")]
 use crate::config::global_config;
-use crate::request::{CreatingRequestErrors, HttpPath};
+#[cfg(feature = "server")]
+use crate::request::CreatingRequestErrors;
+#[cfg(feature = "server")]
+use crate::request::{Method, RequestTarget};
+use crate::request::{HttpPath, Version};
 
+#[cfg(feature = "server")]
 macro_rules! try_increment_index {
     ($index:ident,$len:ident,$last_index_used:ident) => {
           if $index+1 >= $len { return CreatingRequestErrors::InsufficientDataSoReadMore.into()}
@@ -17,78 +23,175 @@ macro_rules! try_increment_index {
 #[derive(Debug)]
 pub struct  HttpFirstLine<'buf>{
     pub(crate) version:&'buf str,
+    pub(crate) typed_version:Version,
     pub(crate) path:HttpPath<'buf>,
     #[cfg(feature = "server")]
-    pub (crate) method:&'buf str,
+    pub (crate) method:Method<'buf>,
+    #[cfg(feature = "server")]
+    pub (crate) target:RequestTarget<'buf>,
 
     /// defining first line length
     pub first_line_length:usize,
 }
 
 
+/// positions of request line parts which were already validated
+///
+/// offsets stay valid when more bytes are appended to the same buffer
+/// ,so [`crate::request::RequestParser`] keeps them between reads instead of scanning the line again
+#[cfg(feature = "server")]
+#[derive(Debug,Clone,Copy)]
+pub (crate) struct FirstLineOffsets {
+    method_end:usize,
+    target_end:usize,
+    typed_version:Version,
+    pub (crate) first_line_length:usize,
+}
+
+#[cfg(feature = "server")]
+impl FirstLineOffsets {
+
+    pub (crate) fn empty()->FirstLineOffsets{
+        FirstLineOffsets {
+            method_end:0,
+            target_end:0,
+            typed_version:Version::Http11,
+            first_line_length:0,
+        }
+    }
+
+    pub (crate) fn typed_version(&self)->Version{
+        self.typed_version
+    }
+}
+
 impl <'buf> HttpFirstLine<'buf> {
 
     #[cfg(feature = "server")]
     #[inline]
     pub (crate) fn from_server(bytes:&'buf[u8]) -> Result<HttpFirstLine<'buf>,CreatingRequestErrors>{
+        let offsets = Self::scan(bytes)?;
+        Self::from_offsets(bytes,offsets)
+    }
+
+    /// validating method ,version and size limits of request line and returning where its parts are
+    /// ,while request-target is classified later in [`HttpFirstLine::from_offsets`]
+    #[cfg(feature = "server")]
+    pub (crate) fn scan(bytes:&[u8]) -> Result<FirstLineOffsets,CreatingRequestErrors>{
         let mut method = None;
-        let mut path = None;
-        let mut version = None;
+        let mut target_end = None;
         let mut last_used_index = 0_usize;
         let total_length = bytes.len();
         let global_conf = global_config();
         for (index,byte) in bytes.iter().enumerate() {
             if method.is_none() {
                 if index >= global_conf.max_method_size { return CreatingRequestErrors::InvalidHttpFormat.into();}
-                match byte {
-                    &b' '=>{
-                        method = Some(&bytes[..index]);
-                        try_increment_index!(index,total_length,last_used_index);
-                        continue
+                if byte == &b' ' {
+                    match Method::from_bytes(&bytes[..index]) {
+                        None => {return CreatingRequestErrors::InvalidHttpFormat.into()}
+                        m => {method = m}
                     }
-                    _ => {}
+                    try_increment_index!(index,total_length,last_used_index);
+                    continue
                 }
             }
-            else if path.is_none() {
-                if index >= global_conf.max_path_size {return CreatingRequestErrors::DangerousInvalidHttpFormat.into();}
-                match byte {
-                    &b' '=>{
-                        path = Some(&bytes[last_used_index..index]);
-                        try_increment_index!(index,total_length,last_used_index);
-                        continue
+            else if target_end.is_none() {
+                if index >= global_conf.max_path_size {return CreatingRequestErrors::DangerousInvalidHttpFormat(None).into();}
+                if byte == &b' ' {
+                    target_end = Some(index);
+                    try_increment_index!(index,total_length,last_used_index);
+                    continue
+                }
+                if byte == &b'\r' {
+                    // HTTP/0.9 simple request is only `GET` with a path and without version
+                    if !global_conf.allow_http09 { return CreatingRequestErrors::InvalidHttpFormat.into() }
+                    let next_index = index + 1 ;
+                    if next_index >= total_length {return CreatingRequestErrors::InsufficientDataSoReadMore.into()}
+                    if bytes[next_index] != b'\n' || method != Some(Method::Get) || index == last_used_index {
+                        return CreatingRequestErrors::InvalidHttpFormat.into()
                     }
-                    _ => {}
+                    return Ok(
+                        FirstLineOffsets {
+                            method_end:3,
+                            target_end:index,
+                            typed_version:Version::Http09,
+                            first_line_length:index + 2
+                        }
+                    )
                 }
             }
-            else if version.is_none(){
+            else if let (Some(method),Some(target_end)) = (method,target_end){
                 let len = index - last_used_index;
-                if len >= global_conf.max_version_size {return CreatingRequestErrors::DangerousInvalidHttpFormat.into();}
-
-                match &byte {
-                    &b'\r'=>{
-                        let next_index = index + 1 ;
-                        if next_index >= total_length {return CreatingRequestErrors::InsufficientDataSoReadMore.into()}
-                        if &bytes[next_index] != &b'\n' {continue;}
-                        version = Some(&bytes[last_used_index..index]);
-                        last_used_index = index + 2;
-                        if let Ok(method) = std::str::from_utf8(method.unwrap()) {
-                            if let Ok(version) = std::str::from_utf8(version.unwrap()) {
-                                return Ok(
-                                    HttpFirstLine {
-                                        method,
-                                        version,
-                                        path:HttpPath::new(path.unwrap()),
-                                        first_line_length:last_used_index
-                                    }
-                                    )
-                            }
+                if len >= global_conf.max_version_size {return CreatingRequestErrors::DangerousInvalidHttpFormat(None).into();}
+
+                if byte == &b'\r' {
+                    let next_index = index + 1 ;
+                    if next_index >= total_length {return CreatingRequestErrors::InsufficientDataSoReadMore.into()}
+                    if bytes[next_index] != b'\n' {continue;}
+                    let typed_version = match Version::from_bytes(&bytes[last_used_index..index]) {
+                        None => {return CreatingRequestErrors::InvalidHttpFormat.into()}
+                        Some(v) => {v}
+                    };
+                    return Ok(
+                        FirstLineOffsets {
+                            method_end:method.as_str().len(),
+                            target_end,
+                            typed_version,
+                            first_line_length:index + 2
                         }
-                        return CreatingRequestErrors::DangerousInvalidHttpFormat.into()
-                    }
-                    _ => {}
+                    )
                 }
             }
         }
         CreatingRequestErrors::InsufficientDataSoReadMore.into()
     }
+
+    /// building first line from offsets returned by [`HttpFirstLine::scan`] over the same bytes
+    /// ,which only classifies request-target because everything else was validated while scanning
+    #[cfg(feature = "server")]
+    pub (crate) fn from_offsets(bytes:&'buf [u8],offsets:FirstLineOffsets)->Result<HttpFirstLine<'buf>,CreatingRequestErrors>{
+        let method = Method::from_validated(&bytes[..offsets.method_end]);
+        let path = &bytes[offsets.method_end + 1..offsets.target_end];
+        let target = RequestTarget::from_first_line(path,&method)?;
+        let version = match offsets.typed_version {
+            Version::Http09 => {
+                if !matches!(target,RequestTarget::Origin {..}) { return CreatingRequestErrors::InvalidHttpFormat.into() }
+                ""
+            }
+            // SAFETY: versions are only accepted when they are ascii `HTTP/1.x`
+            _ => {unsafe{std::str::from_utf8_unchecked(&bytes[offsets.target_end + 1..offsets.first_line_length - 2])}}
+        };
+        Ok(
+            HttpFirstLine {
+                method,
+                target,
+                version,
+                typed_version:offsets.typed_version,
+                path:HttpPath::new(path),
+                first_line_length:offsets.first_line_length
+            }
+        )
+    }
+
+    /// getting http request method
+    #[cfg(feature = "server")]
+    pub fn method(&self)->Method<'buf>{
+        self.method
+    }
+
+    /// getting request-target form with its components
+    #[cfg(feature = "server")]
+    pub fn target(&self)->&RequestTarget<'buf>{
+        &self.target
+    }
+
+    /// getting http request version
+    pub fn version(&self)->&'buf str{
+        self.version
+    }
+
+    /// getting http request version as [`Version`] enum
+    pub fn typed_version(&self)->Version{
+        self.typed_version
+    }
 }
\ No newline at end of file
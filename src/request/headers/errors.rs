@@ -1,6 +1,8 @@
+use std::str::Utf8Error;
+
 
 /// defining errors while parsing bytes to http headers
-#[derive(Debug)]
+#[derive(Debug,PartialEq)]
  pub enum CreatingHeadersErrors {
     /// for invalid headers format
     InvalidFormat,
@@ -9,11 +11,51 @@
     /// if headers payload not enough
     ReadMore,
     /// when incoming header contains malicious attack or payload
-    DangerousInvalidFormat
+    DangerousInvalidFormat,
+    /// when strict mode detects headers that could be interpreted differently by other http parsers
+    RequestSmuggling(SmugglingReasons),
+    /// when message has more header lines than the headers count capacity
+    /// and [`crate::config::HeadersOverflowPolicy::Error`] is used
+    TooManyHeaders,
  }
 
+/// defining why headers were rejected as request smuggling attempt
+#[derive(Debug,PartialEq)]
+pub enum SmugglingReasons {
+    /// `Content-Length` header was sent more than once
+    DuplicateContentLength,
+    /// `Content-Length` value is not a plain decimal number without leading zeros
+    InvalidContentLength,
+    /// both `Content-Length` and `Transfer-Encoding` headers were sent
+    ContentLengthWithTransferEncoding,
+    /// `Transfer-Encoding` applies chunked more than once or its final coding is not chunked
+    InvalidTransferEncoding,
+    /// header name is followed by white spaces before the colon
+    WhitespaceBeforeColon,
+    /// `Content-Length` or `Transfer-Encoding` value is continued with obsolete line folding
+    FoldedFramingHeader,
+}
+
+#[allow(clippy::from_over_into)]
 impl<T> Into<Result<T,CreatingHeadersErrors>> for  CreatingHeadersErrors {
     fn into(self) -> Result<T, CreatingHeadersErrors> {
         Err(self)
     }
+}
+
+/// defining errors while reading header value as borrowed str
+#[derive(Debug,PartialEq)]
+pub enum ReadingHeaderValueErrors {
+    /// value contains obs-text bytes which are not valid utf-8
+    InvalidUtf8(Utf8Error),
+    /// value is continued with obsolete line folding ,so its raw bytes contain line breaks
+    /// and it could only be read unfolded with [`crate::request::headers::HeaderValue::to_unfolded_str`]
+    ObsoleteLineFolding,
+}
+
+#[allow(clippy::from_over_into)]
+impl<T> Into<Result<T,ReadingHeaderValueErrors>> for  ReadingHeaderValueErrors {
+    fn into(self) -> Result<T, ReadingHeaderValueErrors> {
+        Err(self)
+    }
 }
\ No newline at end of file
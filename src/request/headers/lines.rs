@@ -0,0 +1,63 @@
+use crate::request::headers::HeaderLine;
+
+/// small vector of header lines which keeps the first `N` lines inline without allocation
+/// and moves the following lines to the heap
+///
+/// all parsed header lines are stored in it ,but lines only move to the heap
+/// when [`crate::config::HeadersOverflowPolicy::SpillToHeap`] is used
+/// ,so `N` could be chosen for the common case while outliers up to
+/// [`crate::config::HttpHConfigurations::max_headers_count`] are still accepted
+#[derive(Debug)]
+pub struct HeaderLines<'buf,const N:usize> {
+    inline:[HeaderLine<'buf>;N],
+    len:usize,
+    heap:Vec<HeaderLine<'buf>>,
+}
+
+impl<'buf,const N:usize> Default for HeaderLines<'buf,N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'buf,const N:usize> HeaderLines<'buf,N> {
+
+    /// creating empty lines without allocation
+    pub fn new()->HeaderLines<'buf,N>{
+        HeaderLines {
+            inline:[HeaderLine::empty();N],
+            len:0,
+            heap:Vec::new(),
+        }
+    }
+
+    /// appending line inline or on the heap when inline capacity is full
+    #[cfg(any(feature = "server", feature = "client"))]
+    pub (crate) fn push(&mut self,line:HeaderLine<'buf>){
+        match self.inline.get_mut(self.len) {
+            Some(slot) => {*slot = line}
+            None => {self.heap.push(line)}
+        }
+        self.len += 1;
+    }
+
+    /// returning lines count
+    pub fn len(&self)->usize{
+        self.len
+    }
+
+    /// checking if there is no line
+    pub fn is_empty(&self)->bool{
+        self.len == 0
+    }
+
+    /// checking if inline capacity was exceeded and lines were moved to the heap
+    pub fn is_spilled(&self)->bool{
+        !self.heap.is_empty()
+    }
+
+    /// iterating over lines in their order
+    pub fn iter(&self)->impl Iterator<Item=&HeaderLine<'buf>>{
+        self.inline[..self.len.min(N)].iter().chain(self.heap.iter())
+    }
+}
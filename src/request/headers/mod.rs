@@ -1,15 +1,28 @@
 mod errors;
+mod lines;
 
+use std::borrow::Cow;
 use std::collections::HashMap;
+use std::str::Utf8Error;
 pub use errors::*;
+/// using small vector of header lines
+pub use lines::*;
+#[cfg(feature = "server")]
 use crate::config::global_config;
+#[cfg(any(feature = "server", feature = "client"))]
+use crate::config::{HeadersOverflowPolicy, HttpHConfigurations};
+#[cfg(any(feature = "server", feature = "client"))]
+use crate::utils::{is_field_value_byte, is_token_byte, trim_ows};
 use crate::request::CreatingRequestErrors;
 
 /// including all parsed headers
 #[derive(Debug)]
  pub struct HttpHeaders<'buf,const HL: usize>
  {
-     lines:[HeaderLine<'buf>;HL],
+     /// `HL` lines are inline and the following ones are on the heap
+     /// when [`crate::config::HeadersOverflowPolicy::SpillToHeap`] is used
+     lines:HeaderLines<'buf,HL>,
+     dropped_count:usize,
      /// defining content length for public and fast access
      pub content_length:Option<usize>,
      /// defining headers length
@@ -17,26 +30,31 @@ use crate::request::CreatingRequestErrors;
 
  }
 
-macro_rules! try_forward {
-    ($index:ident,$last_index:ident,$total_length:ident) => {
-        try_forward!($index + 1,$last_index,$total_length);
-    };
-    ($index:ident+$v:expr,$last_index:ident,$total_length:ident) => {
-        if($index+$v >= $total_length ) {return CreatingHeadersErrors::ReadMore.into();}
-        $last_index = $index +$v;
+/// finding the '\r' index of the CRLF which ends the line starting at `start`
+/// ,while rejecting bare CR or LF and lines exceeding the headers size limit
+#[cfg(any(feature = "server", feature = "client"))]
+fn find_line_end(bytes:&[u8],start:usize,config:&HttpHConfigurations)->Result<usize,CreatingHeadersErrors>{
+    let Some(lf) = bytes[start..].iter().position(|b| b == &b'\n').map(|i| i + start) else {
+        if bytes.len() > config.max_headers_size { return CreatingHeadersErrors::DangerousInvalidFormat.into() }
+        return CreatingHeadersErrors::ReadMore.into()
     };
+    if lf >= config.max_headers_size { return CreatingHeadersErrors::DangerousInvalidFormat.into() }
+    if lf == start || bytes[lf - 1] != b'\r' || bytes[start..lf - 1].contains(&b'\r') {
+        return CreatingHeadersErrors::InvalidFormat.into()
+    }
+    Ok(lf - 1)
 }
 
 #[inline]
 /// for converting usize bytes to usize object in rust
-fn bytes_to_usize(bytes: &[u8]) -> Option<usize> {
+pub (crate) fn bytes_to_usize(bytes: &[u8]) -> Option<usize> {
     if bytes.is_empty() || bytes.len() > 20 { // usize::MAX is ~20 digits
         return None;
     }
 
     let mut result = 0usize;
     for &byte in bytes {
-        if byte < b'0' || byte > b'9' {
+        if !byte.is_ascii_digit() {
             return None; // Invalid digit
         }
         result = result.checked_mul(10)?.checked_add((byte - b'0') as usize)?;
@@ -44,88 +62,240 @@ fn bytes_to_usize(bytes: &[u8]) -> Option<usize> {
     Some(result)
 }
 
+/// reading `Content-Length` value which could be comma separated list of identical values
+#[cfg(any(feature = "server", feature = "client"))]
+fn lenient_content_length(value:&[u8])->Option<usize>{
+    let mut content_length = None;
+    for element in value.split(|b| b == &b',') {
+        let length = bytes_to_usize(trim_ows(element))?;
+        match content_length {
+            Some(previous) if previous != length => {return None}
+            _ => {content_length = Some(length)}
+        }
+    }
+    content_length
+}
+
+/// reading `Content-Length` value which must be single decimal number without leading zeros
+#[cfg(any(feature = "server", feature = "client"))]
+fn strict_content_length(value:&[u8])->Result<usize,CreatingHeadersErrors>{
+    if value.len() > 1 && value[0] == b'0' {
+        return CreatingHeadersErrors::RequestSmuggling(SmugglingReasons::InvalidContentLength).into()
+    }
+    match bytes_to_usize(value) {
+        None => {CreatingHeadersErrors::RequestSmuggling(SmugglingReasons::InvalidContentLength).into()}
+        Some(length) => {Ok(length)}
+    }
+}
+
+/// tracking framing headers for strict mode checks
+#[cfg(any(feature = "server", feature = "client"))]
+#[derive(Default)]
+struct StrictFramingState {
+    content_length_lines:usize,
+    has_transfer_encoding:bool,
+    chunked_is_final:bool,
+    chunked_count:usize,
+}
+
+#[cfg(any(feature = "server", feature = "client"))]
+impl StrictFramingState {
+
+    fn on_transfer_encoding(&mut self,value:&[u8])->Result<(),CreatingHeadersErrors>{
+        for coding in value.split(|b| b == &b',') {
+            let coding = coding.split(|b| b == &b';').next().unwrap_or(coding);
+            let coding = trim_ows(coding);
+            if coding.is_empty() { continue }
+            self.has_transfer_encoding = true;
+            self.chunked_is_final = coding.eq_ignore_ascii_case(b"chunked");
+            if self.chunked_is_final { self.chunked_count += 1; }
+        }
+        if self.chunked_count > 1 {
+            return CreatingHeadersErrors::RequestSmuggling(SmugglingReasons::InvalidTransferEncoding).into()
+        }
+        Ok(())
+    }
+
+    fn finish(&self,is_request:bool)->Result<(),CreatingHeadersErrors>{
+        if !self.has_transfer_encoding { return Ok(()) }
+        if self.content_length_lines > 0 {
+            return CreatingHeadersErrors::RequestSmuggling(SmugglingReasons::ContentLengthWithTransferEncoding).into()
+        }
+        // responses could end their body by closing the connection ,so only requests must end with chunked
+        if is_request && !self.chunked_is_final {
+            return CreatingHeadersErrors::RequestSmuggling(SmugglingReasons::InvalidTransferEncoding).into()
+        }
+        Ok(())
+    }
+}
+
 impl<'buf,const HL:usize> HttpHeaders<'buf,HL>{
-    /// creating new HttpHeaders from incoming bytes
+    /// creating new HttpHeaders from incoming request bytes
+    #[cfg(feature = "server")]
     pub (crate) fn new(bytes:&'buf[u8])->Result<HttpHeaders<'buf,HL>,CreatingHeadersErrors>{
-        let mut lines = [HeaderLine::empty();HL];
-        let mut lines_index =0_usize;
-        let total_length = bytes.len();
-        let global_config = global_config();
-        let mut end_indicator  = 0_u8;
-        let mut last_index = 0_usize;
-        let mut key = None;
-        let mut content_length = None;
-        for (index,byte) in bytes.iter().enumerate() {
-            if index >= global_config.max_headers_size { return CreatingHeadersErrors::DangerousInvalidFormat.into();}
+        Self::parse(bytes,global_config(),true)
+    }
 
-            match byte {
-                &b':' => {
-                    if key.is_some() {continue;}
-                    key = Some(&bytes[last_index..index]);
-                    try_forward!(index+2,last_index,total_length);
-                }
+    /// creating empty headers for HTTP/0.9 simple requests which do not have a headers section
+    #[cfg(feature = "server")]
+    pub (crate) fn empty()->HttpHeaders<'buf,HL>{
+        HttpHeaders {
+            lines:HeaderLines::new(),
+            dropped_count:0,
+            content_length:None,
+            headers_length:0,
+        }
+    }
 
-                &b'\r' => {
-                    end_indicator+=1;
-                    if let Some(k) = key {
-                        match k {
-                            b"Content-Length"=>{content_length = bytes_to_usize(&bytes[last_index..index])}
-                            b"content-length"=>{content_length = bytes_to_usize(&bytes[last_index..index])}
-                            _ =>{}
-                        }
-                        let line = lines.get_mut(lines_index);
-                        if let Some(line) = line {
-                            line.key = std::str::from_utf8(k).unwrap();
-                            line.value = (&bytes[last_index..index]).into();
+    /// creating new HttpHeaders from incoming request or response bytes with the given configurations
+    #[cfg(any(feature = "server", feature = "client"))]
+    pub (crate) fn parse(bytes:&'buf[u8],config:&HttpHConfigurations,is_request:bool)->Result<HttpHeaders<'buf,HL>,CreatingHeadersErrors>{
+        let mut lines = HeaderLines::new();
+        let mut dropped_count = 0_usize;
+        let mut framing = StrictFramingState::default();
+        let mut content_length = None;
+        // bytes always start with the '\n' of the first line
+        let mut line_start = 1_usize;
+        loop {
+            let line_end = find_line_end(bytes,line_start,config)?;
+            if line_end == line_start {
+                // empty line ends the headers section
+                if config.strict_mode { framing.finish(is_request)?; }
+                return Ok(
+                    HttpHeaders {
+                        lines,
+                        dropped_count,
+                        headers_length:line_end + 1,
+                        content_length
+                    }
+                )
+            }
+            let line = &bytes[line_start..line_end];
+            // white space at the start of the first field line could hide a header from other parsers
+            if line[0] == b' ' || line[0] == b'\t' { return CreatingHeadersErrors::InvalidFormat.into() }
+            let Some(colon) = line.iter().position(|b| b == &b':') else {
+                return CreatingHeadersErrors::InvalidFormat.into()
+            };
+            let k = &line[..colon];
+            if k.last().is_some_and(|b| b == &b' ' || b == &b'\t') {
+                return CreatingHeadersErrors::RequestSmuggling(SmugglingReasons::WhitespaceBeforeColon).into()
+            }
+            // names must be tokens (RFC 9110 section 5.1) ,so they are always ascii
+            if k.is_empty() || !k.iter().all(|b| is_token_byte(*b)) { return CreatingHeadersErrors::InvalidFormat.into() }
+            let Ok(key) = std::str::from_utf8(k) else { return CreatingHeadersErrors::InvalidFormat.into() };
+            if !line[colon + 1..].iter().all(|b| is_field_value_byte(*b)) { return CreatingHeadersErrors::InvalidFormat.into() }
+            let value_start = line_start + colon + 1;
+            let mut value_end = line_end;
+            let mut next_line_start = line_end + 2;
+            // lines starting with white space continue the previous value as obsolete line folding
+            loop {
+                match bytes.get(next_line_start) {
+                    None => {return CreatingHeadersErrors::ReadMore.into()}
+                    Some(b' ') | Some(b'\t') => {
+                        if !config.allow_obsolete_line_folding { return CreatingHeadersErrors::InvalidFormat.into() }
+                        value_end = find_line_end(bytes,next_line_start,config)?;
+                        if !bytes[next_line_start..value_end].iter().all(|b| is_field_value_byte(*b)) {
+                            return CreatingHeadersErrors::InvalidFormat.into()
                         }
-                        key = None;
-                        lines_index +=1;
+                        next_line_start = value_end + 2;
                     }
-                    try_forward!(index,last_index,total_length);
+                    Some(_) => {break}
                 }
-                &b'\n' => {
-                    end_indicator+=1;
-                    if end_indicator >= 4 {
-                        return Ok(
-                            HttpHeaders {
-                                lines,
-                                headers_length:index,
-                                content_length
-                            }
-                        )
-                    }
-                    else if index+1  >= bytes.len() { return CreatingHeadersErrors::ReadMore.into()}
-                    last_index=index + 1;
+            }
+            let value = trim_ows(&bytes[value_start..value_end]);
+            let is_framing_header = k.eq_ignore_ascii_case(b"content-length") || k.eq_ignore_ascii_case(b"transfer-encoding");
+            // framing must be read the same way by every parser in the chain ,even in lenient mode
+            if value_end != line_end && is_framing_header {
+                return CreatingHeadersErrors::RequestSmuggling(SmugglingReasons::FoldedFramingHeader).into()
+            }
+            if k.eq_ignore_ascii_case(b"content-length") {
+                framing.content_length_lines += 1;
+                if !config.strict_mode {
+                    // differing values make the length unknown ,like body framing resolution does
+                    let length = lenient_content_length(value);
+                    content_length = if framing.content_length_lines == 1 || (length.is_some() && length == content_length) {
+                        length
+                    } else {
+                        None
+                    };
+                }
+                else if framing.content_length_lines > 1 {
+                    return CreatingHeadersErrors::RequestSmuggling(SmugglingReasons::DuplicateContentLength).into()
                 }
-                _ => {
-                    end_indicator = 0;
+                else {
+                    content_length = Some(strict_content_length(value)?);
+                }
+            }
+            else if config.strict_mode && k.eq_ignore_ascii_case(b"transfer-encoding") {
+                framing.on_transfer_encoding(value)?;
+            }
+            let line = HeaderLine {
+                key,
+                value:value.into(),
+            };
+            if lines.len() < HL {
+                lines.push(line);
+            }
+            else {
+                match config.headers_overflow_policy {
+                    HeadersOverflowPolicy::Error => {return CreatingHeadersErrors::TooManyHeaders.into()}
+                    // body framing and host are read from stored lines ,so dropping them would hide the message boundaries
+                    HeadersOverflowPolicy::Drop if is_framing_header || k.eq_ignore_ascii_case(b"host") => {
+                        return CreatingHeadersErrors::TooManyHeaders.into()
+                    }
+                    HeadersOverflowPolicy::Drop => {dropped_count += 1}
+                    HeadersOverflowPolicy::SpillToHeap if lines.len() < config.max_headers_count => {lines.push(line)}
+                    HeadersOverflowPolicy::SpillToHeap => {return CreatingHeadersErrors::TooManyHeaders.into()}
                 }
             }
+            line_start = next_line_start;
         }
-
-        Err(CreatingHeadersErrors::ReadMore)
     }
 
     /// for getting specific header value based on header key
     pub fn get(&self,key:&str)->Option<&HeaderValue<'buf>>{
-        for line in &self.lines {
+        for line in self.all_lines() {
             if line.key == key {
                 return  Some(&line.value)
             }
         }
-        for line in &self.lines {
-            if line.key.to_lowercase() == key.to_lowercase() {
+        for line in self.all_lines() {
+            if line.key.eq_ignore_ascii_case(key) {
                 return  Some(&line.value)
             }
         }
         None
     }
 
+    /// iterating over inline and spilled lines
+    fn all_lines(&self)->impl Iterator<Item=&HeaderLine<'buf>>{
+        self.lines.iter()
+    }
+
+    /// getting header lines storage ,for example to check if it was spilled to the heap
+    pub fn header_lines(&self)->&HeaderLines<'buf,HL>{
+        &self.lines
+    }
+
+    /// returning how many header lines were ignored because of [`crate::config::HeadersOverflowPolicy::Drop`]
+    pub fn dropped_count(&self)->usize{
+        self.dropped_count
+    }
+
+
+    /// for getting all values of repeated header key like `Transfer-Encoding` or `Set-Cookie`
+    /// ,keys are compared case-insensitively
+    pub fn get_all<'h>(&'h self,key:&'h str)->impl Iterator<Item=&'h HeaderValue<'buf>> + 'h{
+        self.all_lines()
+            .filter(move |line| line.key.eq_ignore_ascii_case(key))
+            .map(|line| &line.value)
+    }
 
     /// getting key value as ['&str']
+    /// ,which is `None` when key does not exist ,its value is not valid utf-8 or it is folded
     pub fn get_as_str(&self,key:&str)->Option<&'buf str>{
         if let Some(value) =  self.get(key) {
-            return Some(value.to_str());
+            return value.to_str().ok();
         }
         None
     }
@@ -137,9 +307,14 @@ impl<'buf,const HL:usize> HttpHeaders<'buf,HL>{
         }
         None
     }
+    /// deserializing header lines into typed struct ,see [`crate::de::from_headers`]
+    pub fn deserialize<T:serde::Deserialize<'buf>>(&self)->Result<T,crate::de::DeserializingErrors>{
+        crate::de::from_headers(self)
+    }
+
     /// getting all header lines
     pub fn lines(&self)->Vec<&HeaderLine<'buf>>{
-        self.lines.iter().filter(|x| !x.key.is_empty()).collect()
+        self.all_lines().collect()
     }
 
 }
@@ -149,9 +324,11 @@ impl From<CreatingHeadersErrors> for CreatingRequestErrors {
     fn from(value: CreatingHeadersErrors) -> Self {
         match value {
             CreatingHeadersErrors::InvalidFormat => {CreatingRequestErrors::InvalidHttpFormat}
-            CreatingHeadersErrors::MaxHeadersSizeReachedOut => {CreatingRequestErrors::DangerousInvalidHttpFormat}
+            CreatingHeadersErrors::MaxHeadersSizeReachedOut => {CreatingRequestErrors::DangerousInvalidHttpFormat(None)}
             CreatingHeadersErrors::ReadMore => { CreatingRequestErrors::InsufficientDataSoReadMore }
-            CreatingHeadersErrors::DangerousInvalidFormat => {CreatingRequestErrors::DangerousInvalidHttpFormat}
+            CreatingHeadersErrors::DangerousInvalidFormat => {CreatingRequestErrors::DangerousInvalidHttpFormat(None)}
+            CreatingHeadersErrors::RequestSmuggling(reason) => {CreatingRequestErrors::DangerousInvalidHttpFormat(Some(reason))}
+            CreatingHeadersErrors::TooManyHeaders => {CreatingRequestErrors::InvalidHeadersError(value)}
         }
     }
 }
@@ -180,6 +357,10 @@ impl <'buf> HeaderLine<'buf> {
 #[derive(Debug,Copy,Clone)]
 pub struct HeaderValue<'buf> {
     bytes:&'buf [u8],
+    /// checked once when value is created ,so ascii values are read as str without validation
+    ascii:bool,
+    /// value contains obsolete line folding ,so it must be unfolded before being read as text
+    folded:bool,
 }
 
 
@@ -187,16 +368,22 @@ impl <'buf> HeaderValue<'buf> {
 
     pub (crate) fn new(bytes:&'buf [u8])->HeaderValue<'buf>{
         HeaderValue {
-            bytes
+            bytes,
+            ascii:bytes.is_ascii(),
+            // values never contain line breaks unless they were folded
+            folded:bytes.contains(&b'\n'),
         }
     }
 
     /// separate headers values like Accept: text/html, application/xhtml+xml, application/xml;q=0.9, */*;q=0.8
     /// into values like ["text/html","application/xhtml+xml",...]
+    ///
+    /// values which are not valid utf-8 or are folded do not have any injected values
     pub fn all_injected_values(&self)->Vec<&'buf str>{
-        let v:&'buf str = self.into();
-        let s = v.split(", ").collect();
-        s
+        match self.to_str() {
+            Ok(v) => {v.split(", ").collect()}
+            Err(_) => {vec![]}
+        }
     }
 
     /// returning all injected values which means values that separated by ','
@@ -205,7 +392,7 @@ impl <'buf> HeaderValue<'buf> {
         let values = self.all_injected_values();
         let mut vs = vec![];
         for v in values {
-            let h = HeaderVWithParams::new(v.as_bytes());
+            let h = HeaderVWithParams::new(v);
             if let Ok(v) = h {
                 vs.push(v);
             }
@@ -213,33 +400,119 @@ impl <'buf> HeaderValue<'buf> {
         vs
     }
 
+    /// returning the raw bytes of header value
+    pub fn as_bytes(&self)->&'buf [u8]{
+        self.bytes
+    }
+
+    /// checking if header value only contains ascii bytes ,which means [`HeaderValue::to_str`] could not fail
+    pub fn is_ascii(&self)->bool{
+        self.ascii
+    }
+
+    /// replacing obsolete line folding with single space (RFC 9112 section 5.2)
+    /// ,which only exists when [`crate::config::HttpHConfigurations::allow_obsolete_line_folding`] is enabled
+    ///
+    /// borrows value bytes when there is no folding
+    pub fn unfold(&self)->Cow<'buf,[u8]>{
+        if !self.folded { return Cow::Borrowed(self.bytes) }
+        let mut unfolded = Vec::with_capacity(self.bytes.len());
+        let mut in_fold = false;
+        for byte in self.bytes {
+            match byte {
+                b'\r' | b'\n' => {
+                    while unfolded.last().is_some_and(|b| b == &b' ' || b == &b'\t') { unfolded.pop(); }
+                    in_fold = true;
+                }
+                b' ' | b'\t' if in_fold => {}
+                byte => {
+                    if in_fold {
+                        unfolded.push(b' ');
+                        in_fold = false;
+                    }
+                    unfolded.push(*byte);
+                }
+            }
+        }
+        Cow::Owned(unfolded)
+    }
+
     /// reading header value as str with zero copy of bytes
-    pub fn to_str(&self)->&'buf str {
-        unsafe{std::str::from_utf8_unchecked(self.bytes)}
+    ///
+    /// ascii values are returned without any validation
+    /// ,while values with obs-text bytes must be valid utf-8
+    /// ,and folded values are rejected because their bytes contain line breaks
+    pub fn to_str(&self)->Result<&'buf str,ReadingHeaderValueErrors> {
+        if self.folded { return ReadingHeaderValueErrors::ObsoleteLineFolding.into() }
+        if self.ascii {
+            // SAFETY: ascii bytes are always valid utf-8
+            return Ok(unsafe{std::str::from_utf8_unchecked(self.bytes)})
+        }
+        std::str::from_utf8(self.bytes).map_err(ReadingHeaderValueErrors::InvalidUtf8)
+    }
+
+    /// reading header value as str after replacing obsolete line folding with single space
+    ///
+    /// borrows value bytes when there is no folding
+    pub fn to_unfolded_str(&self)->Result<Cow<'buf,str>,Utf8Error>{
+        match self.unfold() {
+            Cow::Borrowed(bytes) => {
+                if self.ascii {
+                    // SAFETY: ascii bytes are always valid utf-8
+                    return Ok(Cow::Borrowed(unsafe{std::str::from_utf8_unchecked(bytes)}))
+                }
+                std::str::from_utf8(bytes).map(Cow::Borrowed)
+            }
+            Cow::Owned(bytes) => {String::from_utf8(bytes).map(Cow::Owned).map_err(|e| e.utf8_error())}
+        }
+    }
+
+    /// reading unfolded header value as str while replacing invalid utf-8 sequences with `U+FFFD`
+    ///
+    /// borrows value bytes when they are valid utf-8 and not folded
+    pub fn to_str_lossy(&self)->Cow<'buf,str>{
+        match self.unfold() {
+            Cow::Borrowed(bytes) => {String::from_utf8_lossy(bytes)}
+            Cow::Owned(bytes) => {Cow::Owned(String::from_utf8_lossy(&bytes).into_owned())}
+        }
+    }
+
+    /// decoding unfolded header value as ISO-8859-1 (latin-1) ,which is how legacy clients send obs-text bytes
+    /// ,so every byte is mapped to the unicode character with the same code point
+    ///
+    /// borrows value bytes when they are ascii and not folded
+    pub fn to_latin1(&self)->Cow<'buf,str>{
+        if self.ascii && !self.folded {
+            // SAFETY: ascii bytes are always valid utf-8
+            return Cow::Borrowed(unsafe{std::str::from_utf8_unchecked(self.bytes)})
+        }
+        Cow::Owned(self.unfold().iter().map(|b| *b as char).collect())
     }
 }
 
 
-impl<'buf> Into<HeaderValue<'buf>> for &'buf [u8] {
-    fn into(self) -> HeaderValue<'buf> {
-        HeaderValue::new(self)
+impl<'buf> From<&'buf [u8]> for HeaderValue<'buf> {
+    fn from(value: &'buf [u8]) -> Self {
+        HeaderValue::new(value)
     }
 }
 
-impl <'buf> Into<HeaderVWithParams<'buf>> for &'buf str {
-    fn into(self) -> HeaderVWithParams<'buf> {
-        HeaderVWithParams::new(self.as_bytes()).unwrap()
+impl <'buf> From<&'buf str> for HeaderVWithParams<'buf> {
+    fn from(value: &'buf str) -> Self {
+        HeaderVWithParams::new(value).unwrap()
     }
 }
 
-impl <'buf> Into<&'buf str> for &HeaderValue<'buf> {
-    fn into(self) -> &'buf str {
-       unsafe { std::str::from_utf8_unchecked(self.bytes)}
+impl <'buf> TryFrom<&HeaderValue<'buf>> for &'buf str {
+    type Error = ReadingHeaderValueErrors;
+    fn try_from(value: &HeaderValue<'buf>) -> Result<Self,Self::Error> {
+        value.to_str()
     }
 }
-impl  Into<String> for HeaderValue<'_> {
-    fn into(self) -> String{
-       unsafe { std::str::from_utf8_unchecked(self.bytes)}.to_string()
+impl TryFrom<HeaderValue<'_>> for String {
+    type Error = ReadingHeaderValueErrors;
+    fn try_from(value: HeaderValue<'_>) -> Result<Self,Self::Error> {
+        value.to_str().map(|v| v.to_string())
     }
 }
 
@@ -247,7 +520,7 @@ impl  Into<String> for HeaderValue<'_> {
 /// for structuring headers values with params
 #[derive(Debug)]
 pub struct HeaderVWithParams<'buf> {
-    data:&'buf [u8],
+    data:&'buf str,
     value:&'buf str,
     /// all value parameters if existed
     pub params:HashMap<Option<&'buf str>,&'buf [u8]>
@@ -255,20 +528,21 @@ pub struct HeaderVWithParams<'buf> {
 
 
 
+// all indices are next to ascii delimiters ,so slicing text on them is always on char boundaries
 macro_rules! set_value_to_header {
-    ($value:ident,$key:ident,$map:ident,$last_index:ident,$index:ident,$bytes:ident) => {
+    ($value:ident,$key:ident,$map:ident,$last_index:ident,$index:ident,$text:ident) => {
          if let Some(k) = $key {
                         $map.insert(
-                            Some(unsafe{std::str::from_utf8_unchecked(k)}),
-                            &$bytes[$last_index..$index]
+                            Some(k),
+                            &$text.as_bytes()[$last_index..$index]
                         );
                         $key = None;
                     }else if $value.is_none() {
-                        $value = Some(unsafe { std::str::from_utf8_unchecked(&$bytes[$last_index..$index]) });
+                        $value = Some(&$text[$last_index..$index]);
                     } else {
                         $map.insert(
                             None,
-                            &$bytes[$last_index..$index]
+                            &$text.as_bytes()[$last_index..$index]
                         );
                     }
                     $last_index = $index;
@@ -277,25 +551,24 @@ macro_rules! set_value_to_header {
 impl<'buf> HeaderVWithParams<'buf>{
 
     /// generating new
-    pub (crate) fn new(bytes:&'buf [u8])->Result<HeaderVWithParams<'buf>,()>{
+    pub (crate) fn new(text:&'buf str)->Result<HeaderVWithParams<'buf>,()>{
 
+        let bytes = text.as_bytes();
         let mut map = HashMap::new();
         let mut value = None;
         let mut key = None;
         let mut last_index = 0_usize;
         for (index,byte)  in bytes.iter().enumerate() {
             match byte {
-                &b';'=>{
-                    set_value_to_header!(value,key,map,last_index,index,bytes);
+                b';'=>{
+                    set_value_to_header!(value,key,map,last_index,index,text);
                 }
-                &b' '=>{
-                    if index == last_index+1 {
-                        if index + 1 >= bytes.len() { return Err(())}
-                        last_index = index + 1;
-                    }
+                b' ' if index == last_index+1 => {
+                    if index + 1 >= bytes.len() { return Err(())}
+                    last_index = index + 1;
                 }
-                &b'='=>{
-                    key = Some(&bytes[last_index..index]);
+                b'='=>{
+                    key = Some(&text[last_index..index]);
                     if index + 1 >= bytes.len() { return Err(())}
                     last_index = index+1;
                 }
@@ -304,15 +577,15 @@ impl<'buf> HeaderVWithParams<'buf>{
         }
         if let Some(k) = key {
             map.insert(
-                Some(unsafe{std::str::from_utf8_unchecked(k)}),
+                Some(k),
                 &bytes[last_index..]
             );
         }
        Ok(
            HeaderVWithParams {
-               data:bytes,
+               data:text,
                value: match value {
-                   None => {unsafe{std::str::from_utf8_unchecked(bytes)}}
+                   None => {text}
                    Some(v) => {v}
                },
                params:map
@@ -328,7 +601,7 @@ impl<'buf> HeaderVWithParams<'buf>{
 
     /// return the whole value as str
     pub fn whole_value_as_str(&self)->&'buf str{
-        unsafe {std::str::from_utf8_unchecked(self.data)}
+        self.data
     }
 
 
@@ -0,0 +1,34 @@
+#[cfg(feature = "server")]
+use crate::request::headers::HttpHeaders;
+#[cfg(feature = "server")]
+use crate::request::{CreatingRequestErrors, HttpFirstLine, RequestTarget, Version};
+use crate::uri::Authority;
+
+/// parsing `Host` header value which is authority without userinfo (RFC 9110 section 7.2)
+pub (crate) fn parse_host(value:&[u8])->Option<Authority<'_>>{
+    let value = std::str::from_utf8(value).ok()?;
+    let authority = Authority::parse(value).ok()?;
+    if authority.userinfo().is_some() || authority.host_str().is_empty() { return None }
+    Some(authority)
+}
+
+/// checking that HTTP/1.1 requests have exactly one valid `Host` header
+/// ,and that it matches the authority of absolute-form request-target
+#[cfg(feature = "server")]
+pub (crate) fn validate_host<const HC:usize>(first_line:&HttpFirstLine<'_>,headers:&HttpHeaders<'_,HC>)->Result<(),CreatingRequestErrors>{
+    if first_line.typed_version() == Version::Http09 { return Ok(()) }
+    let mut hosts = headers.get_all("host");
+    let host = match (hosts.next(),hosts.next()) {
+        (None,_) if first_line.typed_version() == Version::Http10 => {return Ok(())}
+        (Some(host),None) => {host}
+        _ => {return CreatingRequestErrors::InvalidHostHeader.into()}
+    };
+    let Some(host) = parse_host(host.as_bytes()) else { return CreatingRequestErrors::InvalidHostHeader.into() };
+    if let RequestTarget::Absolute(uri) = first_line.target() {
+        let Some(authority) = uri.authority() else { return CreatingRequestErrors::InvalidHostHeader.into() };
+        if !authority.host_str().eq_ignore_ascii_case(host.host_str()) || authority.port() != host.port() {
+            return CreatingRequestErrors::InvalidHostHeader.into()
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,100 @@
+use std::fmt::{Display, Formatter};
+use crate::utils::is_token_byte;
+
+/// http request method (RFC 9110 section 9)
+///
+/// methods are case-sensitive ,so `get` is parsed as extension method and not as [`Method::Get`]
+#[derive(Debug,Clone,Copy,PartialEq,Eq,Hash)]
+pub enum Method<'buf> {
+    /// `GET` method
+    Get,
+    /// `HEAD` method
+    Head,
+    /// `POST` method
+    Post,
+    /// `PUT` method
+    Put,
+    /// `DELETE` method
+    Delete,
+    /// `CONNECT` method
+    Connect,
+    /// `OPTIONS` method
+    Options,
+    /// `TRACE` method
+    Trace,
+    /// `PATCH` method (RFC 5789)
+    Patch,
+    /// any other method which is a valid http token like `PROPFIND`
+    Extension(&'buf str),
+}
+
+impl<'buf> Method<'buf> {
+
+    /// parsing method bytes ,returning [`None`] when they are empty or contain non token characters
+    pub fn from_bytes(bytes:&'buf [u8])->Option<Method<'buf>>{
+        if bytes.is_empty() || !bytes.iter().all(|b| is_token_byte(*b)) { return None }
+        Some(Self::from_validated(bytes))
+    }
+
+    /// creating method from bytes which were already accepted by [`Method::from_bytes`]
+    pub (crate) fn from_validated(bytes:&'buf [u8])->Method<'buf>{
+        match bytes {
+            b"GET" => {Method::Get}
+            b"HEAD" => {Method::Head}
+            b"POST" => {Method::Post}
+            b"PUT" => {Method::Put}
+            b"DELETE" => {Method::Delete}
+            b"CONNECT" => {Method::Connect}
+            b"OPTIONS" => {Method::Options}
+            b"TRACE" => {Method::Trace}
+            b"PATCH" => {Method::Patch}
+            // SAFETY: token characters are always ascii
+            _ => {Method::Extension(unsafe{std::str::from_utf8_unchecked(bytes)})}
+        }
+    }
+
+    /// returning method as it appears in request first line
+    pub fn as_str(&self)->&'buf str{
+        match self {
+            Method::Get => {"GET"}
+            Method::Head => {"HEAD"}
+            Method::Post => {"POST"}
+            Method::Put => {"PUT"}
+            Method::Delete => {"DELETE"}
+            Method::Connect => {"CONNECT"}
+            Method::Options => {"OPTIONS"}
+            Method::Trace => {"TRACE"}
+            Method::Patch => {"PATCH"}
+            Method::Extension(method) => {method}
+        }
+    }
+
+    /// checking if method is safe which means it is read-only (RFC 9110 section 9.2.1)
+    pub fn is_safe(&self)->bool{
+        matches!(self,Method::Get | Method::Head | Method::Options | Method::Trace)
+    }
+
+    /// checking if sending the same request multiple times has the same effect as sending it once
+    /// (RFC 9110 section 9.2.2) ,extension methods are never assumed to be idempotent
+    pub fn is_idempotent(&self)->bool{
+        self.is_safe() || matches!(self,Method::Put | Method::Delete)
+    }
+}
+
+impl Display for Method<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl PartialEq<str> for Method<'_> {
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl PartialEq<&str> for Method<'_> {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
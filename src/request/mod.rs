@@ -1,23 +1,61 @@
+#[cfg(feature = "server")]
 mod enums;
 pub mod headers;
 mod first_line;
+mod method;
+mod version;
+mod path;
+mod target;
+mod host;
+#[cfg(feature = "client")]
+mod builder;
+#[cfg(feature = "server")]
+mod parser;
+#[cfg(feature = "server")]
+mod pipeline;
 
-use std::collections::HashMap;
 use std::fmt::Debug;
 #[cfg(feature = "write_logs")]
 use std::io::Write;
 /// using all first line implementations
 pub use first_line::*;
+/// using http method enum
+pub use method::*;
+/// using http version enum
+pub use version::*;
+/// using http path and percent-decoding implementations
+pub use path::*;
+/// using request-target forms
+pub use target::*;
+/// using request builder for writing outbound requests
+#[cfg(feature = "client")]
+pub use builder::*;
+/// using resumable request parser
+#[cfg(feature = "server")]
+pub use parser::*;
+/// using pipelined requests iterator
+#[cfg(feature = "server")]
+pub use pipeline::*;
+#[cfg(feature = "server")]
 use crate::request::CreatingRequestErrors::InvalidHeadersError;
+#[cfg(feature = "server")]
 use crate::request::enums::CreatingRequestSteps;
-use crate::request::headers::{ CreatingHeadersErrors, HttpHeaders};
+use crate::request::headers::{ CreatingHeadersErrors, HttpHeaders, SmugglingReasons};
+use crate::body::{BodyFraming, BodyFramingErrors, ChunkedDecodeResult, ChunkedDecoder};
+use crate::config::global_config;
+use crate::request::host::parse_host;
+#[cfg(feature = "server")]
+use crate::request::host::validate_host;
 
 /// for parsing http request bytes
 #[derive(Debug)]
 pub struct HttpRequest<'buf,const HC:usize>{
     http_first_line: HttpFirstLine<'buf>,
     headers:HttpHeaders<'buf,HC>,
-
+    /// all given bytes starting from the request first line
+    bytes:&'buf [u8],
+    /// body length which was already resolved ,so chunked bodies are not decoded again
+    body_length:Option<usize>,
 }
 
 impl<'buf,const HC:usize> HttpRequest<'buf, HC> {
@@ -26,17 +64,58 @@ impl<'buf,const HC:usize> HttpRequest<'buf, HC> {
     #[cfg(feature = "server")]
     /// getting http request method
     pub fn method(&self)->&'buf str{
+        self.http_first_line.method.as_str()
+    }
+
+    #[cfg(feature = "server")]
+    /// getting http request method as [`Method`] enum
+    pub fn typed_method(&self)->Method<'buf>{
         self.http_first_line.method
     }
     /// getting http request version
     pub fn version(&self)->&'buf str{
         self.http_first_line.version
     }
-    /// getting http request path
+
+    /// getting http request version as [`Version`] enum
+    pub fn typed_version(&self)->Version{
+        self.http_first_line.typed_version
+    }
+
+    /// checking if the connection should stay open after this request
+    /// from its version default and `Connection` header options
+    pub fn keep_alive(&self)->bool{
+        is_keep_alive(self.typed_version(),&self.headers)
+    }
+    /// getting http request path ,which is the whole request-target as it was sent
     pub fn path(&self)->&HttpPath<'buf>{
         &self.http_first_line.path
     }
 
+    #[cfg(feature = "server")]
+    /// getting request-target form with its components
+    pub fn target(&self)->&RequestTarget<'buf>{
+        &self.http_first_line.target
+    }
+
+    /// getting host name and optional port which the request is sent to ,for example for virtual hosts dispatching
+    ///
+    /// authority of absolute-form request-target is preferred over `Host` header as RFC 9112 requires
+    /// ,and [`None`] is returned when neither exists ,the `Host` header is invalid or repeated
+    /// ,because lenient mode accepts requests with many `Host` headers and choosing one of them
+    /// could route the request differently than a proxy in front of the server did
+    pub fn host(&self)->Option<(&'buf str,Option<u16>)>{
+        #[cfg(feature = "server")]
+        if let RequestTarget::Absolute(uri) = self.target() {
+            let authority = uri.authority()?;
+            return Some((authority.host_str(),authority.port()))
+        }
+        let mut hosts = self.headers.get_all("host");
+        let (Some(host),None) = (hosts.next(),hosts.next()) else { return None };
+        let host = parse_host(host.as_bytes())?;
+        Some((host.host_str(),host.port()))
+    }
+
     #[inline]
     /// http first line
     pub const fn first_line(&self)->&HttpFirstLine<'buf>{
@@ -49,39 +128,97 @@ impl<'buf,const HC:usize> HttpRequest<'buf, HC> {
         &self.headers
     }
 
+    /// resolving where the request body ends from `Content-Length` and `Transfer-Encoding` headers
+    pub fn body_framing(&self)->Result<BodyFraming,BodyFramingErrors>{
+        BodyFraming::from_request(self.typed_version(),&self.headers)
+    }
+
+    #[inline]
+    /// returning the length of request first line and headers including the empty line after them
+    pub fn head_length(&self)->usize{
+        self.http_first_line.first_line_length + self.headers.headers_length
+    }
+
+    /// returning request body bytes as they were received ,so chunked bodies are still encoded
+    ///
+    /// returns [`CreatingRequestErrors::InsufficientDataSoReadMore`] when the body was not fully received yet
+    pub fn raw_body(&self)->Result<&'buf [u8],CreatingRequestErrors>{
+        let head_length = self.head_length();
+        let body_length = self.raw_body_length()?;
+        Ok(&self.bytes[head_length..head_length + body_length])
+    }
+
+    /// returning how many bytes the whole request occupies which is
+    /// `first_line_length + headers_length + body length` ,so the next pipelined request starts right after it
+    ///
+    /// returns [`CreatingRequestErrors::InsufficientDataSoReadMore`] when the body was not fully received yet
+    pub fn consumed_length(&self)->Result<usize,CreatingRequestErrors>{
+        Ok(self.head_length() + self.raw_body_length()?)
+    }
+
+    fn raw_body_length(&self)->Result<usize,CreatingRequestErrors>{
+        if let Some(body_length) = self.body_length { return Ok(body_length) }
+        let body = &self.bytes[self.head_length()..];
+        match self.body_framing()? {
+            BodyFraming::None | BodyFraming::CloseDelimited => {Ok(0)}
+            BodyFraming::Length(length) => {
+                if let Some(max) = global_config().max_body_size {
+                    if length > max { return CreatingRequestErrors::DangerousInvalidHttpFormat(None).into() }
+                }
+                if body.len() < length { return CreatingRequestErrors::InsufficientDataSoReadMore.into() }
+                Ok(length)
+            }
+            BodyFraming::Chunked => {
+                let mut decoder = ChunkedDecoder::new();
+                let mut position = 0_usize;
+                loop {
+                    match decoder.decode(&body[position..])? {
+                        ChunkedDecodeResult::Data { consumed, .. } => {position += consumed;}
+                        ChunkedDecodeResult::Finished { consumed, .. } => {return Ok(position + consumed)}
+                    }
+                }
+            }
+        }
+    }
+
     /// creating http request structure from given bytes with zero copies
+    /// ,where `HC` is the inline headers capacity
     #[cfg(feature = "server")]
-    pub  fn from_incoming_bytes<const N:usize>(mut bytes:&'buf [u8])->Result<HttpRequest<'buf,N>,CreatingRequestErrors>{
+    pub  fn from_incoming_bytes(mut bytes:&'buf [u8])->Result<Self,CreatingRequestErrors>{
+        let request_bytes = bytes;
         let mut step = CreatingRequestSteps::init();
          let mut first_line = None;
 
         #[cfg(feature = "write_logs")]
-        let path =  format!("./logs/{}.txt",chrono::Local::now().format("%Y%m%d_%H%M%S").to_string());
+        let path =  format!("./logs/{}.txt",chrono::Local::now().format("%Y%m%d_%H%M%S"));
         #[cfg(feature = "write_logs")]
         let mut file = std::fs::File::create(
             path.as_str()
-        ).expect(format!("can not create log file with {path}").as_str());
+        ).unwrap_or_else(|_| panic!("can not create log file with {path}"));
         #[cfg(feature = "write_logs")]
         {
 
 
-            file.write(format!("\n\n method invoked : HttpRequest::from_incoming_bytes \n bytes: \n {:?} \n\n",
+            file.write_all(format!("\n\n method invoked : HttpRequest::from_incoming_bytes \n bytes: \n {:?} \n\n",
              String::from_utf8_lossy(bytes)
             ).as_bytes()).unwrap();
         }
         loop {
             #[cfg(feature = "write_logs")]
             {
-                file.write(format!("\r\nmatching step start : {:?} \r\n",step).as_bytes()).unwrap();
+                file.write_all(format!("\r\nmatching step start : {:?} \r\n",step).as_bytes()).unwrap();
             }
             match step {
                 CreatingRequestSteps::FirstLine => {
                     let fl = HttpFirstLine::from_server(bytes)?;
+                    if fl.typed_version() == Version::Http09 {
+                        return HttpRequest::from_head(fl,HttpHeaders::empty(),request_bytes)
+                    }
                     let index:usize = fl.first_line_length-1;
                     first_line = Some(fl);
                     #[cfg(feature = "write_logs")]
                     {
-                        file.write(format!("\r\n first line bytes detected: {:?} \r\n while left is   {:?} \r\n",
+                        file.write_all(format!("\r\n first line bytes detected: {:?} \r\n while left is   {:?} \r\n",
                          String::from_utf8_lossy(&bytes[..index]),
                          String::from_utf8_lossy(&bytes[index..]),
                         ).as_bytes()).unwrap();
@@ -97,17 +234,14 @@ impl<'buf,const HC:usize> HttpRequest<'buf, HC> {
 
                             #[cfg(feature = "write_logs")]
                             {
-                                file.write(format!("\r\n headers bytes detected: {:?} \r\n while left is   {:?} \r\n",
+                                file.write_all(format!("\r\n headers bytes detected: {:?} \r\n while left is   {:?} \r\n",
                                                    String::from_utf8_lossy(&bytes[..h.headers_length]),
                                                    String::from_utf8_lossy(&bytes[h.headers_length..]),
                                 ).as_bytes()).unwrap();
 
                             }
                             let first_line = first_line.unwrap();
-                            Ok(HttpRequest {
-                                http_first_line: first_line,
-                                headers:h,
-                            })
+                            HttpRequest::from_head(first_line,h,request_bytes)
                         }
                         Err(e) => {
 
@@ -117,6 +251,7 @@ impl<'buf,const HC:usize> HttpRequest<'buf, HC> {
                                         CreatingRequestErrors::InsufficientDataSoReadMore
                                     )
                                 }
+                                CreatingHeadersErrors::RequestSmuggling(_) => {Err(e.into())}
                                 _ => {
                                     InvalidHeadersError(
                                         e
@@ -133,19 +268,47 @@ impl<'buf,const HC:usize> HttpRequest<'buf, HC> {
 
 
 
-    /// creating http request with fast
+    /// iterating over all pipelined requests in the given bytes
     #[cfg(feature = "server")]
+    pub fn pipelined(bytes:&'buf [u8])->PipelinedRequests<'buf,HC>{
+        PipelinedRequests::new(bytes)
+    }
 
-    pub fn from_bytes<const N:usize>(bytes:&'buf [u8])->Result<HttpRequest<'buf,N>,CreatingRequestErrors>{
+    /// creating http request with fast
+    /// ,where `HC` is the inline headers capacity
+    #[cfg(feature = "server")]
+    pub fn from_bytes(bytes:&'buf [u8])->Result<Self,CreatingRequestErrors>{
 
         let first_line = HttpFirstLine::from_server(bytes)?;
 
-        let headers = HttpHeaders::<N>::new(&bytes[first_line.first_line_length-1..])?;
+        let headers = match first_line.typed_version() {
+            Version::Http09 => {HttpHeaders::empty()}
+            _ => {HttpHeaders::new(&bytes[first_line.first_line_length-1..])?}
+        };
+
+        HttpRequest::from_head(first_line,headers,bytes)
+    }
 
+    /// resolving body length once and keeping it for [`HttpRequest::raw_body`] and [`HttpRequest::consumed_length`]
+    #[cfg(feature = "server")]
+    pub (crate) fn resolve_body_length(&mut self)->Result<usize,CreatingRequestErrors>{
+        let body_length = self.raw_body_length()?;
+        self.body_length = Some(body_length);
+        Ok(body_length)
+    }
+
+    /// creating request from parsed head and applying strict mode checks which need both first line and headers
+    #[cfg(feature = "server")]
+    pub (crate) fn from_head(http_first_line:HttpFirstLine<'buf>,headers:HttpHeaders<'buf,HC>,bytes:&'buf [u8])->Result<HttpRequest<'buf,HC>,CreatingRequestErrors>{
+        if global_config().strict_mode {
+            validate_host(&http_first_line,&headers)?;
+        }
         Ok(
             HttpRequest {
                 headers,
-                http_first_line:first_line,
+                http_first_line,
+                bytes,
+                body_length:None,
             }
         )
     }
@@ -162,88 +325,29 @@ pub enum CreatingRequestErrors{
     InsufficientDataSoReadMore,
 
     /// when someone trying to attack your server
-    DangerousInvalidHttpFormat,
+    /// ,with the reason when headers have ambiguous framing or syntax which could be used for request smuggling
+    DangerousInvalidHttpFormat(Option<SmugglingReasons>),
     /// when parsing http headers contains errors
-    InvalidHeadersError(CreatingHeadersErrors)
+    InvalidHeadersError(CreatingHeadersErrors),
+    /// in strict mode when HTTP/1.1 request does not have exactly one valid `Host` header
+    /// ,or when it does not match the authority of absolute-form request-target
+    InvalidHostHeader,
 }
 
+#[allow(clippy::from_over_into)]
 impl<R> Into<Result<R,CreatingRequestErrors>> for CreatingRequestErrors {
     fn into(self) -> Result<R, CreatingRequestErrors> {
         Err(self)
     }
 }
 
-/// http path structure
-#[derive(Debug)]
-pub struct HttpPath<'buf> {
-    bytes:&'buf [u8],
-}
-
-impl<'buf> HttpPath<'buf> {
-
-    pub (crate) fn new(bytes:&'buf [u8])->HttpPath<'buf>{
-        HttpPath {
-            bytes
-        }
-    }
-
-    /// converting total path to ['&str']
-    pub fn to_str(&self) -> &'buf str {
-        std::str::from_utf8(self.bytes).unwrap()
-    }
-
-    /// returning the actual bytes of path
-    pub fn get_bytes(&self)->&'buf [u8]{
-        self.bytes
-    }
-
-    #[inline(always)]
-    pub fn to_query(&self)->Option<(&'buf str,HashMap<&'buf str,&'buf str>)> {
-        let mut map = HashMap::new();
-        let path  = self.bytes;
-        let mut clean_path = path;
-        let mut cursor = 0_usize;
-        let mut key = None;
-        let length = path.len();
-        for (index,q) in path.iter().enumerate() {
-            match q {
-                b'?'=>{
-                    clean_path = &path[..index];
-                    cursor = index+1;
-                    if cursor >= length { return  None }
-                }
-                b'='=>{
-                    key = Some(&path[cursor..index]);
-                    cursor = index+1;
-                    if cursor >= length {
-                        if map.is_empty() {return  None}
-                        return Some((unsafe{std::str::from_utf8_unchecked(clean_path)},map))
-                    }
-                }
-                b'&'=>{
-                    if let Some(k) = key {
-                        unsafe {map.insert(std::str::from_utf8_unchecked(k),std::str::from_utf8_unchecked(&path[cursor..index]))};
-                        cursor=index+1;
-                        key = None;
-                    }
-                }
-                _=>{}
-            }
-        }
-        if let Some(k ) = key {
-            unsafe {map.insert(std::str::from_utf8_unchecked(k),std::str::from_utf8_unchecked(&path[cursor..]));}
-        }
-        if map.is_empty() {return None}
-        return Some((unsafe{std::str::from_utf8_unchecked(clean_path)},map))
-    }
-
-}
-
-
-
-#[cfg(test)]
+#[cfg(all(test, feature = "server"))]
 mod test {
-    use crate::request::HttpRequest;
+    use crate::body::{BodyFraming, BodyFramingErrors};
+    use crate::config::{HeadersOverflowPolicy, HttpHConfigurations};
+    use crate::request::headers::{CreatingHeadersErrors, HttpHeaders, ReadingHeaderValueErrors, SmugglingReasons};
+    use crate::request::{CreatingRequestErrors, HttpFirstLine, HttpRequest, Method, RequestParser, RequestTarget, Version};
+    use crate::request::host::validate_host;
 
 
     fn generate_requests() -> Vec<Vec<u8>> {
@@ -338,8 +442,359 @@ mod test {
         check_request(r_bytes);
     }
 
+    #[test]
+    fn test_request_without_headers() {
+        let r_bytes = b"GET /home HTTP/1.0\r\n\r\n";
+        let request:HttpRequest<16> = HttpRequest::<16>::from_bytes(r_bytes).unwrap();
+        assert_eq!(request.headers.headers_length, 2);
+    }
+
+    #[test]
+    fn test_typed_method() {
+        let request = HttpRequest::<4>::from_bytes(b"PATCH /user/5 HTTP/1.1\r\nHost: a\r\n\r\n").unwrap();
+        assert_eq!(request.typed_method(), Method::Patch);
+        assert!(!request.typed_method().is_idempotent());
+        let request = HttpRequest::<4>::from_bytes(b"PROPFIND /dav HTTP/1.1\r\nHost: a\r\n\r\n").unwrap();
+        assert_eq!(request.typed_method(), Method::Extension("PROPFIND"));
+        assert_eq!(request.method(), "PROPFIND");
+        assert!(Method::Head.is_safe() && Method::Delete.is_idempotent() && !Method::Post.is_safe());
+        for r_bytes in [&b"G(T / HTTP/1.1\r\n\r\n"[..], b" / HTTP/1.1\r\n\r\n", b"G\x01T / HTTP/1.1\r\n\r\n"] {
+            assert!(matches!(HttpRequest::<4>::from_bytes(r_bytes), Err(CreatingRequestErrors::InvalidHttpFormat)));
+        }
+    }
+
+    #[test]
+    fn test_typed_version() {
+        let request = HttpRequest::<4>::from_bytes(b"GET / HTTP/1.1\r\nHost: a\r\n\r\n").unwrap();
+        assert_eq!(request.typed_version(), Version::Http11);
+        assert!(request.keep_alive());
+        let request = HttpRequest::<4>::from_bytes(b"GET / HTTP/1.1\r\nConnection: Close\r\n\r\n").unwrap();
+        assert!(!request.keep_alive());
+        let request = HttpRequest::<4>::from_bytes(b"GET / HTTP/1.0\r\nHost: a\r\n\r\n").unwrap();
+        assert_eq!(request.typed_version(), Version::Http10);
+        assert!(!request.keep_alive());
+        let request = HttpRequest::<4>::from_bytes(b"GET / HTTP/1.0\r\nConnection: keep-alive\r\n\r\n").unwrap();
+        assert!(request.keep_alive());
+        let request = HttpRequest::<4>::from_bytes(b"GET / HTTP/1.2\r\nHost: a\r\n\r\n").unwrap();
+        assert_eq!(request.typed_version(), Version::Http11);
+        // HTTP/0.9 is disabled by default
+        for r_bytes in [&b"GET / HTTP/9.9garbage\r\n\r\n"[..], b"GET / HTTP/2.0\r\n\r\n", b"GET / http/1.1\r\n\r\n", b"POST /old\r\n", b"GET /old\r\n"] {
+            assert!(matches!(HttpRequest::<4>::from_bytes(r_bytes), Err(CreatingRequestErrors::InvalidHttpFormat)));
+        }
+        let request = HttpRequest::<4>::from_bytes(b"POST / HTTP/1.0\r\nTransfer-Encoding: chunked\r\n\r\n").unwrap();
+        assert_eq!(request.body_framing(), Err(BodyFramingErrors::InvalidTransferEncoding));
+    }
+
+    #[test]
+    fn test_request_target_forms() {
+        let request = HttpRequest::<4>::from_bytes(b"GET /x?a=1 HTTP/1.1\r\nHost: a\r\n\r\n").unwrap();
+        assert_eq!(*request.target(), RequestTarget::Origin { path: "/x", query: Some("a=1") });
+        let request = HttpRequest::<4>::from_bytes(b"GET http://example.com:8080/x?a=1 HTTP/1.1\r\nHost: example.com\r\n\r\n").unwrap();
+        let target = request.target();
+        assert_eq!((target.scheme(), target.authority().map(|a| a.as_str()), target.path(), target.query()),
+                   (Some("http"), Some("example.com:8080"), Some("/x"), Some("a=1")));
+        assert_eq!(target.authority().unwrap().port(), Some(8080));
+        let request = HttpRequest::<4>::from_bytes(b"GET http://example.com HTTP/1.1\r\n\r\n").unwrap();
+        assert_eq!(request.target().path(), Some("/"));
+        let request = HttpRequest::<4>::from_bytes(b"CONNECT example.com:443 HTTP/1.1\r\n\r\n").unwrap();
+        assert_eq!(request.target().authority().map(|a| (a.host_str(), a.port())), Some(("example.com", Some(443))));
+        let request = HttpRequest::<4>::from_bytes(b"OPTIONS * HTTP/1.1\r\n\r\n").unwrap();
+        assert_eq!(*request.target(), RequestTarget::Asterisk);
+        for r_bytes in [
+            &b"GET * HTTP/1.1\r\n\r\n"[..], b"CONNECT /x HTTP/1.1\r\n\r\n", b"CONNECT example.com HTTP/1.1\r\n\r\n",
+            b"GET example.com:443 HTTP/1.1\r\n\r\n", b"GET /x#frag HTTP/1.1\r\n\r\n", b"GET http:/x HTTP/1.1\r\n\r\n",
+            b"GET /\xff HTTP/1.1\r\n\r\n", b"CONNECT user@example.com:443 HTTP/1.1\r\n\r\n", b"GET http://ex^ample/ HTTP/1.1\r\n\r\n", b"GET http:///x HTTP/1.1\r\n\r\n", b"CONNECT :443 HTTP/1.1\r\n\r\n",
+        ] {
+            assert!(matches!(HttpRequest::<4>::from_bytes(r_bytes), Err(CreatingRequestErrors::InvalidHttpFormat)));
+        }
+    }
+
+    #[test]
+    fn test_deserialize_headers() {
+        #[derive(Debug, serde::Deserialize)]
+        struct Headers<'a> {
+            host: &'a str,
+            #[serde(rename = "x-request-id")]
+            request_id: u64,
+            #[serde(rename = "accept")]
+            accepts: Vec<&'a str>,
+            #[serde(rename = "x-debug")]
+            debug: Option<bool>,
+        }
+        let request = HttpRequest::<8>::from_bytes(b"GET / HTTP/1.1\r\nHost: example.com\r\nX-Request-Id: 17\r\nAccept: text/html\r\nAccept: */*\r\nX-Other: \xff\r\n\r\n").unwrap();
+        let headers: Headers = request.headers().deserialize().unwrap();
+        assert_eq!((headers.host, headers.request_id, headers.accepts, headers.debug), ("example.com", 17, vec!["text/html", "*/*"], None));
+        let request = HttpRequest::<8>::from_bytes(b"GET / HTTP/1.1\r\nHost: example.com\r\nX-Request-Id: abc\r\n\r\n").unwrap();
+        let error = request.headers().deserialize::<Headers>().unwrap_err();
+        assert!(error.to_string().contains("`x-request-id`"));
+    }
+
+    fn framing(r_bytes: &[u8]) -> Result<BodyFraming, BodyFramingErrors> {
+        HttpRequest::<16>::from_bytes(r_bytes).unwrap().body_framing()
+    }
+
+    #[test]
+    fn test_request_body_framing() {
+        assert_eq!(framing(b"GET / HTTP/1.1\r\nHost: a\r\n\r\n"), Ok(BodyFraming::None));
+        assert_eq!(framing(b"POST / HTTP/1.1\r\nHost: a\r\nContent-Length: 12\r\n\r\n"), Ok(BodyFraming::Length(12)));
+        assert_eq!(framing(b"POST / HTTP/1.1\r\nHost: a\r\nContent-Length: 12, 12\r\n\r\n"), Ok(BodyFraming::Length(12)));
+        assert_eq!(framing(b"POST / HTTP/1.1\r\nHost: a\r\nTransfer-Encoding: gzip, Chunked\r\n\r\n"), Ok(BodyFraming::Chunked));
+    }
+
+    #[test]
+    fn test_request_body_framing_errors() {
+        assert_eq!(framing(b"POST / HTTP/1.1\r\nHost: a\r\nContent-Length: 3\r\nTransfer-Encoding: chunked\r\n\r\n"), Err(BodyFramingErrors::ConflictingFraming));
+        assert_eq!(framing(b"POST / HTTP/1.1\r\nHost: a\r\nContent-Length: 3\r\ncontent-length: 4\r\n\r\n"), Err(BodyFramingErrors::InvalidContentLength));
+        assert_eq!(framing(b"POST / HTTP/1.1\r\nHost: a\r\nContent-Length: -3\r\n\r\n"), Err(BodyFramingErrors::InvalidContentLength));
+        assert_eq!(framing(b"POST / HTTP/1.1\r\nHost: a\r\nTransfer-Encoding: chunked, gzip\r\n\r\n"), Err(BodyFramingErrors::InvalidTransferEncoding));
+        assert_eq!(framing(b"POST / HTTP/1.1\r\nHost: a\r\nTransfer-Encoding: chunked\r\nTransfer-Encoding: chunked\r\n\r\n"), Err(BodyFramingErrors::InvalidTransferEncoding));
+        assert_eq!(framing(b"POST / HTTP/1.0\r\nHost: a\r\nTransfer-Encoding: chunked\r\n\r\n"), Err(BodyFramingErrors::InvalidTransferEncoding));
+    }
+
+    fn strict_headers(r_bytes: &[u8]) -> Result<HttpHeaders<'_, 16>, CreatingHeadersErrors> {
+        let config = HttpHConfigurations { strict_mode: true, ..HttpHConfigurations::default() };
+        let first_line = HttpFirstLine::from_server(r_bytes).unwrap();
+        HttpHeaders::<16>::parse(&r_bytes[first_line.first_line_length - 1..], &config, true)
+    }
+
+    fn strict_host(r_bytes: &[u8]) -> Result<(), CreatingRequestErrors> {
+        let first_line = HttpFirstLine::from_server(r_bytes).unwrap();
+        validate_host(&first_line, &strict_headers(r_bytes).unwrap())
+    }
+
+    #[test]
+    fn test_host() {
+        let request = HttpRequest::<4>::from_bytes(b"GET / HTTP/1.1\r\nHost: Example.com:8080\r\n\r\n").unwrap();
+        assert_eq!(request.host(), Some(("Example.com", Some(8080))));
+        let request = HttpRequest::<4>::from_bytes(b"GET / HTTP/1.1\r\nHost: [::1]\r\n\r\n").unwrap();
+        assert_eq!(request.host(), Some(("::1", None)));
+        let request = HttpRequest::<4>::from_bytes(b"GET http://proxy.target/x HTTP/1.1\r\nHost: other\r\n\r\n").unwrap();
+        assert_eq!(request.host(), Some(("proxy.target", None)));
+        let request = HttpRequest::<4>::from_bytes(b"GET / HTTP/1.1\r\nHost: user@a\r\n\r\n").unwrap();
+        assert_eq!(request.host(), None);
+        let request = HttpRequest::<4>::from_bytes(b"GET / HTTP/1.0\r\n\r\n").unwrap();
+        assert_eq!(request.host(), None);
+        let request = HttpRequest::<4>::from_bytes(b"GET / HTTP/1.1\r\nHost: a\r\nHost: b\r\n\r\n").unwrap();
+        assert_eq!(request.host(), None);
+    }
+
+    #[test]
+    fn test_strict_host() {
+        assert!(strict_host(b"GET / HTTP/1.1\r\nHost: a.com\r\n\r\n").is_ok());
+        assert!(strict_host(b"GET / HTTP/1.0\r\n\r\n").is_ok());
+        assert!(strict_host(b"GET http://A.com:81/ HTTP/1.1\r\nHost: a.com:81\r\n\r\n").is_ok());
+        for r_bytes in [
+            &b"GET / HTTP/1.1\r\nAccept: */*\r\n\r\n"[..],
+            b"GET / HTTP/1.1\r\nHost: a.com\r\nHost: b.com\r\n\r\n",
+            b"GET / HTTP/1.0\r\nHost: a.com\r\nHost: a.com\r\n\r\n",
+            b"GET / HTTP/1.1\r\nHost: \r\n\r\n",
+            b"GET / HTTP/1.1\r\nHost: a b\r\n\r\n",
+            b"GET http://a.com/ HTTP/1.1\r\nHost: b.com\r\n\r\n",
+            b"GET http://a.com:81/ HTTP/1.1\r\nHost: a.com\r\n\r\n",
+        ] {
+            assert!(matches!(strict_host(r_bytes), Err(CreatingRequestErrors::InvalidHostHeader)), "{}", String::from_utf8_lossy(r_bytes));
+        }
+    }
+
+    fn headers_with<'a>(r_bytes: &'a [u8], config: &'a HttpHConfigurations) -> Result<HttpHeaders<'a, 8>, CreatingHeadersErrors> {
+        let first_line = HttpFirstLine::from_server(r_bytes).unwrap();
+        HttpHeaders::<8>::parse(&r_bytes[first_line.first_line_length - 1..], config, true)
+    }
+
+    #[test]
+    fn test_field_line_ows() {
+        let config = HttpHConfigurations::default();
+        let headers = headers_with(b"GET / HTTP/1.1\r\nHost:example.com\r\nKey: \t  value  \t\r\nEmpty:\r\nContent-Length:  5 \r\n\r\n", &config).unwrap();
+        assert_eq!(headers.get_as_str("host"), Some("example.com"));
+        assert_eq!(headers.get_as_str("key"), Some("value"));
+        assert_eq!(headers.get_as_str("empty"), Some(""));
+        assert_eq!(headers.content_length, Some(5));
+        assert_eq!(headers.headers_length, 67);
+        for r_bytes in [
+            &b"GET / HTTP/1.1\r\nHost\t: a\r\n\r\n"[..],
+            b"GET / HTTP/1.1\r\nHost : a\r\n\r\n",
+        ] {
+            assert_eq!(headers_with(r_bytes, &config).err(), Some(CreatingHeadersErrors::RequestSmuggling(SmugglingReasons::WhitespaceBeforeColon)));
+        }
+        for r_bytes in [
+            &b"GET / HTTP/1.1\r\n Host: a\r\n\r\n"[..],
+            b"GET / HTTP/1.1\r\nHost: a\nX: b\r\n\r\n",
+            b"GET / HTTP/1.1\r\nHost: a\rX: b\r\n\r\n",
+            b"GET / HTTP/1.1\r\nNoColon\r\n\r\n",
+        ] {
+            assert_eq!(headers_with(r_bytes, &config).err(), Some(CreatingHeadersErrors::InvalidFormat));
+        }
+        assert_eq!(headers_with(b"GET / HTTP/1.1\r\nHost: a\r\n", &config).err(), Some(CreatingHeadersErrors::ReadMore));
+        assert_eq!(headers_with(b"GET / HTTP/1.1\r\nHost: a\r\n\r", &config).err(), Some(CreatingHeadersErrors::ReadMore));
+    }
+
+    #[test]
+    fn test_header_characters() {
+        let config = HttpHConfigurations::default();
+        for r_bytes in [
+            &b"GET / HTTP/1.1\r\n: empty-name\r\n\r\n"[..],
+            b"GET / HTTP/1.1\r\nX(Y): a\r\n\r\n",
+            b"GET / HTTP/1.1\r\nX@Y: a\r\n\r\n",
+            b"GET / HTTP/1.1\r\nX\xffY: a\r\n\r\n",
+            b"GET / HTTP/1.1\r\nX-Value: a\x00b\r\n\r\n",
+            b"GET / HTTP/1.1\r\nX-Value: a\x7fb\r\n\r\n",
+            b"GET / HTTP/1.1\r\nX-Value: a\x1bb\r\n\r\n",
+        ] {
+            assert_eq!(headers_with(r_bytes, &config).err(), Some(CreatingHeadersErrors::InvalidFormat));
+        }
+        let headers = headers_with(b"GET / HTTP/1.1\r\nX_Y-Z!#$%&'*+.^`|~09: caf\xe9 \t \"quoted\"\r\n\r\n", &config).unwrap();
+        assert_eq!(headers.lines()[0].key, "X_Y-Z!#$%&'*+.^`|~09");
+        assert_eq!(headers.get_as_bytes("x_y-z!#$%&'*+.^`|~09"), Some(&b"caf\xe9 \t \"quoted\""[..]));
+        let config = HttpHConfigurations { allow_obsolete_line_folding: true, ..HttpHConfigurations::default() };
+        assert_eq!(headers_with(b"GET / HTTP/1.1\r\nX: a\r\n b\x00\r\n\r\n", &config).err(), Some(CreatingHeadersErrors::InvalidFormat));
+    }
+
+    #[test]
+    fn test_header_value_decoding() {
+        let config = HttpHConfigurations::default();
+        let r_bytes = b"GET / HTTP/1.1\r\nAccept: text/html, */*;q=0.8\r\nX-Latin: caf\xe9\r\nX-Utf8: caf\xc3\xa9\r\n\r\n";
+        let headers = headers_with(r_bytes, &config).unwrap();
+        let accept = headers.get("accept").unwrap();
+        assert!(accept.is_ascii());
+        assert_eq!(accept.to_str(), Ok("text/html, */*;q=0.8"));
+        assert_eq!(accept.all_injected_values(), vec!["text/html", "*/*;q=0.8"]);
+        assert_eq!(accept.all_injected_values_with_params()[1].to_str(), "*/*");
+        assert!(matches!(accept.to_latin1(), std::borrow::Cow::Borrowed("text/html, */*;q=0.8")));
+
+        let latin = headers.get("x-latin").unwrap();
+        assert!(!latin.is_ascii());
+        assert!(latin.to_str().is_err());
+        assert!(<&str>::try_from(latin).is_err());
+        assert!(String::try_from(*latin).is_err());
+        assert_eq!(latin.to_str_lossy(), "caf\u{fffd}");
+        assert_eq!(latin.to_latin1(), "caf\u{e9}");
+        assert!(latin.all_injected_values().is_empty());
+        assert_eq!(headers.get_as_str("x-latin"), None);
+        assert_eq!(headers.get_as_bytes("x-latin"), Some(&b"caf\xe9"[..]));
+
+        let utf8 = headers.get("x-utf8").unwrap();
+        assert_eq!(utf8.to_str(), Ok("caf\u{e9}"));
+        assert_eq!(String::try_from(*utf8).unwrap(), "caf\u{e9}");
+        assert!(matches!(utf8.to_str_lossy(), std::borrow::Cow::Borrowed("caf\u{e9}")));
+        assert_eq!(utf8.to_latin1(), "caf\u{c3}\u{a9}");
+    }
+
+    #[test]
+    fn test_obsolete_line_folding() {
+        let r_bytes = b"GET / HTTP/1.1\r\nX-Folded: first  \r\n   second\r\n\tthird\r\nHost: a\r\n\r\n";
+        assert_eq!(headers_with(r_bytes, &HttpHConfigurations::default()).err(), Some(CreatingHeadersErrors::InvalidFormat));
+        let config = HttpHConfigurations { allow_obsolete_line_folding: true, ..HttpHConfigurations::default() };
+        let headers = headers_with(r_bytes, &config).unwrap();
+        let folded = headers.get("x-folded").unwrap();
+        assert_eq!(folded.unfold().as_ref(), b"first second third");
+        // raw bytes with line breaks are never returned as text
+        assert_eq!(folded.to_str(), Err(ReadingHeaderValueErrors::ObsoleteLineFolding));
+        assert_eq!(headers.get_as_str("x-folded"), None);
+        assert!(folded.all_injected_values().is_empty());
+        assert_eq!(folded.to_unfolded_str().unwrap(), "first second third");
+        assert_eq!(folded.to_str_lossy(), "first second third");
+        assert_eq!(folded.to_latin1(), "first second third");
+        let values: std::collections::HashMap<String, String> = headers.deserialize().unwrap();
+        assert_eq!(values["x-folded"], "first second third");
+        assert_eq!(headers.get_as_str("host"), Some("a"));
+        assert!(matches!(headers.get("host").unwrap().unfold(), std::borrow::Cow::Borrowed(b"a")));
+        assert!(matches!(headers.get("host").unwrap().to_unfolded_str(), Ok(std::borrow::Cow::Borrowed("a"))));
+        assert_eq!(headers_with(b"GET / HTTP/1.1\r\nX: a\r\n ", &config).err(), Some(CreatingHeadersErrors::ReadMore));
+        for r_bytes in [
+            &b"POST / HTTP/1.1\r\nContent-Length:\r\n 5\r\n\r\n"[..],
+            b"POST / HTTP/1.1\r\nTransfer-Encoding: gzip,\r\n chunked\r\n\r\n",
+        ] {
+            assert_eq!(headers_with(r_bytes, &config).err(), Some(CreatingHeadersErrors::RequestSmuggling(SmugglingReasons::FoldedFramingHeader)));
+        }
+    }
+
+    #[test]
+    fn test_headers_overflow_policy() {
+        let r_bytes = b"GET / HTTP/1.1\r\nHost: a\r\nAccept: */*\r\nAuthorization: secret\r\nX-A: 1\r\n\r\n";
+        let parse = |policy| {
+            let config = HttpHConfigurations { headers_overflow_policy: policy, ..HttpHConfigurations::default() };
+            HttpHeaders::<2>::parse(&r_bytes[15..], &config, true)
+        };
+        assert_eq!(parse(HeadersOverflowPolicy::Error).err(), Some(CreatingHeadersErrors::TooManyHeaders));
+        assert!(matches!(HttpRequest::<2>::from_bytes(r_bytes), Err(CreatingRequestErrors::InvalidHeadersError(CreatingHeadersErrors::TooManyHeaders))));
+        let headers = parse(HeadersOverflowPolicy::Drop).unwrap();
+        assert_eq!((headers.lines().len(), headers.dropped_count()), (2, 2));
+        assert!(headers.get("authorization").is_none());
+        let config = HttpHConfigurations { headers_overflow_policy: HeadersOverflowPolicy::Drop, ..HttpHConfigurations::default() };
+        for r_bytes in [
+            &b"POST / HTTP/1.1\r\nHost: a\r\nX-A: 1\r\nTransfer-Encoding: chunked\r\n\r\n5\r\nhello\r\n0\r\n\r\n"[..],
+            b"POST / HTTP/1.1\r\nHost: a\r\nX-A: 1\r\nContent-Length: 5\r\n\r\nhello",
+            b"POST / HTTP/1.1\r\nX-A: 1\r\nX-B: 2\r\nHost: a\r\n\r\n",
+        ] {
+            assert_eq!(HttpHeaders::<2>::parse(&r_bytes[16..], &config, true).err(), Some(CreatingHeadersErrors::TooManyHeaders));
+        }
+        let headers = parse(HeadersOverflowPolicy::SpillToHeap).unwrap();
+        assert_eq!((headers.lines().len(), headers.dropped_count()), (4, 0));
+        assert_eq!(headers.get_as_str("authorization"), Some("secret"));
+        assert_eq!(headers.get_all("x-a").count(), 1);
+        assert!(headers.header_lines().is_spilled());
+        let config = HttpHConfigurations { headers_overflow_policy: HeadersOverflowPolicy::SpillToHeap, max_headers_count: 3, ..HttpHConfigurations::default() };
+        assert_eq!(HttpHeaders::<2>::parse(&r_bytes[15..], &config, true).err(), Some(CreatingHeadersErrors::TooManyHeaders));
+        let headers = HttpHeaders::<8>::parse(&r_bytes[15..], &config, true).unwrap();
+        assert!(!headers.header_lines().is_spilled() && headers.header_lines().len() == 4);
+    }
+
+    #[test]
+    fn test_smuggling_is_dangerous() {
+        assert!(matches!(
+            HttpRequest::<8>::from_bytes(b"POST / HTTP/1.1\r\nHost: a\r\nTransfer-Encoding : chunked\r\n\r\n"),
+            Err(CreatingRequestErrors::DangerousInvalidHttpFormat(Some(SmugglingReasons::WhitespaceBeforeColon)))
+        ));
+        assert!(matches!(
+            RequestParser::new().parse::<8>(b"POST / HTTP/1.1\r\nHost: a\r\nTransfer-Encoding : chunked\r\n\r\n"),
+            Err(CreatingRequestErrors::DangerousInvalidHttpFormat(Some(SmugglingReasons::WhitespaceBeforeColon)))
+        ));
+    }
+
+    #[test]
+    fn test_strict_mode_accepts_plain_requests() {
+        let headers = strict_headers(b"POST / HTTP/1.1\r\nHost: a\r\ncontent-length: 10\r\n\r\n").unwrap();
+        assert_eq!(headers.content_length, Some(10));
+        let headers = strict_headers(b"POST / HTTP/1.1\r\nHost: a\r\nTransfer-Encoding: gzip\r\nTransfer-Encoding: chunked\r\n\r\n").unwrap();
+        assert_eq!(headers.content_length, None);
+    }
+
+    #[test]
+    fn test_strict_mode_rejects_smuggling() {
+        let cases: [(&[u8], SmugglingReasons); 7] = [
+            (b"POST / HTTP/1.1\r\nContent-Length: 5\r\nContent-Length: 5\r\n\r\n", SmugglingReasons::DuplicateContentLength),
+            (b"POST / HTTP/1.1\r\nContent-Length: 5, 5\r\n\r\n", SmugglingReasons::InvalidContentLength),
+            (b"POST / HTTP/1.1\r\nContent-Length: 005\r\n\r\n", SmugglingReasons::InvalidContentLength),
+            (b"POST / HTTP/1.1\r\nContent-Length: 5\r\nTransfer-Encoding: chunked\r\n\r\n", SmugglingReasons::ContentLengthWithTransferEncoding),
+            (b"POST / HTTP/1.1\r\nTransfer-Encoding: chunked, identity\r\n\r\n", SmugglingReasons::InvalidTransferEncoding),
+            (b"POST / HTTP/1.1\r\nTransfer-Encoding: chunked, chunked\r\n\r\n", SmugglingReasons::InvalidTransferEncoding),
+            (b"POST / HTTP/1.1\r\nTransfer-Encoding : chunked\r\n\r\n", SmugglingReasons::WhitespaceBeforeColon),
+        ];
+        for (r_bytes, reason) in cases {
+            assert_eq!(strict_headers(r_bytes).err(), Some(CreatingHeadersErrors::RequestSmuggling(reason)));
+        }
+    }
+
+    #[test]
+    fn test_lenient_content_length() {
+        let request = HttpRequest::<16>::from_bytes(b"POST / HTTP/1.1\r\nCONTENT-LENGTH: 7, 7\r\n\r\n").unwrap();
+        assert_eq!(request.headers().content_length, Some(7));
+        let request = HttpRequest::<16>::from_bytes(b"POST / HTTP/1.1\r\nContent-Length: 7\r\nContent-Length: 7\r\n\r\n").unwrap();
+        assert_eq!(request.headers().content_length, Some(7));
+        for r_bytes in [
+            &b"POST / HTTP/1.1\r\nContent-Length: 3\r\nContent-Length: 40\r\n\r\n"[..],
+            b"POST / HTTP/1.1\r\nContent-Length: 3\r\nContent-Length: 40\r\nContent-Length: 40\r\n\r\n",
+            b"POST / HTTP/1.1\r\nContent-Length: x\r\nContent-Length: 40\r\n\r\n",
+        ] {
+            let request = HttpRequest::<16>::from_bytes(r_bytes).unwrap();
+            // the public length never contradicts body framing
+            assert_eq!(request.headers().content_length, None);
+            assert_eq!(request.body_framing(), Err(BodyFramingErrors::InvalidContentLength));
+        }
+    }
+
     fn check_request(r_bytes: &[u8]) {
-        let request = HttpRequest::<16>::from_bytes::<16>(r_bytes);
+        let request = HttpRequest::<16>::from_bytes(r_bytes);
         match &request {
             Ok(req) => {
                 println!("Method: {:?}", req.method());
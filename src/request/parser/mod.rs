@@ -0,0 +1,197 @@
+use crate::config::global_config;
+use crate::request::enums::CreatingRequestSteps;
+use crate::request::headers::{CreatingHeadersErrors, HttpHeaders};
+use crate::request::first_line::FirstLineOffsets;
+use crate::request::{CreatingRequestErrors, HttpFirstLine, HttpRequest, Version};
+
+/// resumable request parser which keeps its progress between socket reads
+///
+/// unlike [`HttpRequest::from_incoming_bytes`] which scans the whole buffer again on every call
+/// ,the parser remembers the current step and where the previous scan stopped
+/// ,so slow clients sending their headers in small pieces are scanned only once
+///
+/// the caller should always pass the same buffer with the newly read bytes appended to its end
+/// ,and after a request is returned the parser is ready for the next one
+///
+/// # Example
+/// ```
+/// use water_http_utils::request::RequestParser;
+/// let bytes = b"GET /home HTTP/1.1\r\nHost: example.com\r\n\r\n";
+/// let mut parser = RequestParser::new();
+/// assert!(parser.parse::<16>(&bytes[..10]).is_err());
+/// assert!(parser.parse::<16>(&bytes[..30]).is_err());
+/// let request = parser.parse::<16>(bytes).unwrap();
+/// assert_eq!(request.path().to_str(),"/home");
+/// ```
+#[derive(Debug)]
+pub struct RequestParser {
+    step:CreatingRequestSteps,
+    scan_offset:usize,
+    /// first line is validated once when it arrives and only its offsets are kept for the next reads
+    first_line:FirstLineOffsets,
+}
+
+impl Default for RequestParser {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RequestParser {
+
+    /// creating new parser waiting for the first line of request
+    pub fn new()->RequestParser{
+        RequestParser {
+            step:CreatingRequestSteps::init(),
+            scan_offset:0,
+            first_line:FirstLineOffsets::empty(),
+        }
+    }
+
+    /// dropping the current progress ,for example when the connection buffer was cleared
+    pub fn reset(&mut self){
+        *self = Self::new();
+    }
+
+    /// continuing parsing from where the previous call stopped
+    ///
+    /// returns [`CreatingRequestErrors::InsufficientDataSoReadMore`] until the whole request head is available
+    pub fn parse<'buf,const HC:usize>(&mut self,bytes:&'buf [u8])->Result<HttpRequest<'buf,HC>,CreatingRequestErrors>{
+        let global_conf = global_config();
+        loop {
+            match self.step {
+                CreatingRequestSteps::FirstLine => {
+                    // keeping one byte back because CR could be the last received byte
+                    let start = self.scan_offset.saturating_sub(1);
+                    match find_crlf(bytes,start) {
+                        None => {
+                            let max_first_line_length = global_conf.max_method_size + global_conf.max_path_size + global_conf.max_version_size + 2;
+                            if bytes.len() > max_first_line_length {
+                                // letting first line parser decide which limit was exceeded
+                                HttpFirstLine::scan(bytes)?;
+                                return CreatingRequestErrors::DangerousInvalidHttpFormat(None).into()
+                            }
+                            self.scan_offset = bytes.len();
+                            return CreatingRequestErrors::InsufficientDataSoReadMore.into()
+                        }
+                        Some(index) => {
+                            let first_line = scan_first_line(&bytes[..index + 2])?;
+                            if first_line.typed_version() == Version::Http09 {
+                                self.reset();
+                                let http_first_line = HttpFirstLine::from_offsets(bytes,first_line)?;
+                                return HttpRequest::from_head(http_first_line,HttpHeaders::empty(),bytes)
+                            }
+                            self.first_line = first_line;
+                            self.scan_offset = first_line.first_line_length;
+                            self.step = CreatingRequestSteps::Headers;
+                        }
+                    }
+                }
+                CreatingRequestSteps::Headers => {
+                    // headers block ends with empty line and starts with the first line CRLF
+                    // ,so keeping three bytes back is enough to catch CRLFCRLF split between reads
+                    let first_line_length = self.first_line.first_line_length;
+                    let start = self.scan_offset.saturating_sub(3).max(first_line_length - 2);
+                    let end = match find_head_end(bytes,start) {
+                        None => {
+                            if bytes.len() - first_line_length > global_conf.max_headers_size {
+                                return Err(CreatingHeadersErrors::DangerousInvalidFormat.into())
+                            }
+                            self.scan_offset = bytes.len();
+                            return CreatingRequestErrors::InsufficientDataSoReadMore.into()
+                        }
+                        Some(end) => {end}
+                    };
+                    let first_line = self.first_line;
+                    self.reset();
+                    let http_first_line = HttpFirstLine::from_offsets(bytes,first_line)?;
+                    let headers = HttpHeaders::<HC>::new(&bytes[first_line_length - 1..end])?;
+                    return HttpRequest::from_head(http_first_line,headers,bytes)
+                }
+            }
+        }
+    }
+}
+
+/// scanning complete first line ,so missing data means the line itself is invalid
+#[inline]
+fn scan_first_line(bytes:&[u8])->Result<FirstLineOffsets,CreatingRequestErrors>{
+    match HttpFirstLine::scan(bytes) {
+        Err(CreatingRequestErrors::InsufficientDataSoReadMore) => {CreatingRequestErrors::InvalidHttpFormat.into()}
+        result => {result}
+    }
+}
+
+/// returning the index of CR in the first CRLF starting from the given index
+#[inline]
+fn find_crlf(bytes:&[u8],start:usize)->Option<usize>{
+    bytes.get(start..)?.windows(2).position(|w| w == b"\r\n").map(|i| i + start)
+}
+
+/// returning the index right after CRLFCRLF starting from the given index
+#[inline]
+fn find_head_end(bytes:&[u8],start:usize)->Option<usize>{
+    bytes.get(start..)?.windows(4).position(|w| w == b"\r\n\r\n").map(|i| i + start + 4)
+}
+
+
+#[cfg(test)]
+mod test {
+    use crate::request::{CreatingRequestErrors, HttpRequest, RequestParser};
+
+    #[test]
+    fn test_parsing_byte_by_byte() {
+        let bytes = b"POST /submit?x=1 HTTP/1.1\r\nHost: example.com\r\nContent-Length: 4\r\n\r\nbody";
+        let head_length = bytes.len() - 4;
+        let mut parser = RequestParser::new();
+        for end in 1..head_length {
+            assert!(matches!(parser.parse::<8>(&bytes[..end]), Err(CreatingRequestErrors::InsufficientDataSoReadMore)));
+            // scanning never goes back more than few bytes from the previous end
+            assert!(parser.scan_offset == end || parser.scan_offset == parser.first_line.first_line_length);
+        }
+        let request = parser.parse::<8>(&bytes[..head_length]).unwrap();
+        assert_eq!(request.method(), "POST");
+        assert_eq!(request.path().to_str(), "/submit?x=1");
+        assert_eq!(request.headers().get_as_str("host"), Some("example.com"));
+        assert_eq!(request.headers().content_length, Some(4));
+    }
+
+    #[test]
+    fn test_parsing_request_without_headers() {
+        let mut parser = RequestParser::new();
+        assert!(parser.parse::<8>(b"GET / HTTP/1.0\r\n\r").is_err());
+        let request = parser.parse::<8>(b"GET / HTTP/1.0\r\n\r\n").unwrap();
+        assert_eq!(request.version(), "HTTP/1.0");
+        assert!(request.headers().lines().is_empty());
+    }
+
+    #[test]
+    fn test_parser_is_reusable() {
+        let mut parser = RequestParser::new();
+        parser.parse::<8>(b"GET /a HTTP/1.1\r\nHost: a\r\n\r\n").unwrap();
+        let request = parser.parse::<8>(b"GET /b HTTP/1.1\r\nHost: b\r\n\r\n").unwrap();
+        assert_eq!(request.path().to_str(), "/b");
+    }
+
+    #[test]
+    fn test_invalid_first_line() {
+        let mut parser = RequestParser::new();
+        assert!(matches!(parser.parse::<8>(b"POST /only-path\r\n\r\n"), Err(CreatingRequestErrors::InvalidHttpFormat)));
+        assert!(matches!(parser.parse::<8>(b"GET / HTTP/2.0\r\n\r\n"), Err(CreatingRequestErrors::InvalidHttpFormat)));
+        // request-target is classified once when the whole head is available
+        let mut parser = RequestParser::new();
+        assert!(matches!(parser.parse::<8>(b"GET /a#b HTTP/1.1\r\nHost: a\r\n"), Err(CreatingRequestErrors::InsufficientDataSoReadMore)));
+        assert!(matches!(parser.parse::<8>(b"GET /a#b HTTP/1.1\r\nHost: a\r\n\r\n"), Err(CreatingRequestErrors::InvalidHttpFormat)));
+        let request = parser.parse::<8>(b"PROPFIND /a HTTP/1.2\r\nHost: a\r\n\r\n").unwrap();
+        assert_eq!((request.method(), request.version(), request.path().to_str()), ("PROPFIND", "HTTP/1.2", "/a"));
+    }
+
+    #[test]
+    fn test_http09_disabled_by_default() {
+        let mut parser = RequestParser::new();
+        assert!(matches!(parser.parse::<8>(b"GET /index.html\r\n"), Err(CreatingRequestErrors::InvalidHttpFormat)));
+        let mut requests = HttpRequest::<8>::pipelined(b"GET /a\r\nGET /b HTTP/1.1\r\nHost: x\r\n\r\n");
+        assert!(matches!(requests.next(), Some(Err(CreatingRequestErrors::InvalidHttpFormat))));
+        assert!(requests.next().is_none());
+    }
+}
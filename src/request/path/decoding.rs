@@ -0,0 +1,113 @@
+use std::borrow::Cow;
+use crate::request::DecodingPathErrors;
+
+/// decoding `%XX` escapes of path component
+///
+/// `+` is kept as it is because it only means space inside query components
+/// ,and the given bytes are borrowed when they do not contain any escape
+///
+/// # Example
+/// ```
+/// use water_http_utils::request::percent_decode;
+/// assert_eq!(percent_decode(b"/files/my%20doc%2Ftxt").unwrap(),"/files/my doc/txt");
+/// assert_eq!(percent_decode(b"/a+b").unwrap(),"/a+b");
+/// assert!(percent_decode(b"/bad%2").is_err());
+/// ```
+pub fn percent_decode(bytes:&[u8])->Result<Cow<'_,str>,DecodingPathErrors>{
+    decode(bytes,false)
+}
+
+/// decoding query component like key or value ,where `+` means space and `%XX` escapes are decoded
+///
+/// # Example
+/// ```
+/// use water_http_utils::request::percent_decode_query;
+/// assert_eq!(percent_decode_query(b"rust+http%20server").unwrap(),"rust http server");
+/// ```
+pub fn percent_decode_query(bytes:&[u8])->Result<Cow<'_,str>,DecodingPathErrors>{
+    decode(bytes,true)
+}
+
+fn decode(bytes:&[u8],plus_as_space:bool)->Result<Cow<'_,str>,DecodingPathErrors>{
+    let first_encoded = bytes.iter().position(|b| b == &b'%' || (plus_as_space && b == &b'+'));
+    let first_encoded = match first_encoded {
+        None => {
+            return match std::str::from_utf8(bytes) {
+                Ok(s) => {Ok(Cow::Borrowed(s))}
+                Err(_) => {DecodingPathErrors::InvalidUtf8.into()}
+            }
+        }
+        Some(index) => {index}
+    };
+    let mut decoded = Vec::with_capacity(bytes.len());
+    decoded.extend_from_slice(&bytes[..first_encoded]);
+    let mut index = first_encoded;
+    while index < bytes.len() {
+        match bytes[index] {
+            b'%' => {
+                let (Some(high),Some(low)) = (
+                    bytes.get(index + 1).and_then(|b| hex_value(*b)),
+                    bytes.get(index + 2).and_then(|b| hex_value(*b))
+                ) else {
+                    return DecodingPathErrors::InvalidPercentEncoding.into()
+                };
+                decoded.push(high << 4 | low);
+                index += 3;
+            }
+            b'+' if plus_as_space => {
+                decoded.push(b' ');
+                index += 1;
+            }
+            byte => {
+                decoded.push(byte);
+                index += 1;
+            }
+        }
+    }
+    // std utf-8 validation rejects overlong encodings and surrogates
+    match String::from_utf8(decoded) {
+        Ok(s) => {Ok(Cow::Owned(s))}
+        Err(_) => {DecodingPathErrors::InvalidUtf8.into()}
+    }
+}
+
+#[inline]
+fn hex_value(byte:u8)->Option<u8>{
+    match byte {
+        b'0'..=b'9' => {Some(byte - b'0')}
+        b'a'..=b'f' => {Some(byte - b'a' + 10)}
+        b'A'..=b'F' => {Some(byte - b'A' + 10)}
+        _ => {None}
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::borrow::Cow;
+    use crate::request::{percent_decode, percent_decode_query, DecodingPathErrors};
+
+    #[test]
+    fn test_borrows_without_escapes() {
+        assert!(matches!(percent_decode(b"/plain/path"), Ok(Cow::Borrowed("/plain/path"))));
+        assert!(matches!(percent_decode_query(b"a+b"), Ok(Cow::Owned(_))));
+    }
+
+    #[test]
+    fn test_decoding() {
+        assert_eq!(percent_decode(b"/a%2fb%2Fc").unwrap(), "/a/b/c");
+        assert_eq!(percent_decode(b"caf%C3%A9").unwrap(), "café");
+        assert_eq!(percent_decode_query(b"rust+http%20server").unwrap(), "rust http server");
+        assert_eq!(percent_decode_query(b"1%2B1").unwrap(), "1+1");
+    }
+
+    #[test]
+    fn test_invalid_escapes() {
+        for bytes in [&b"%"[..], b"%4", b"%zz", b"a%g1"] {
+            assert_eq!(percent_decode(bytes), Err(DecodingPathErrors::InvalidPercentEncoding));
+        }
+        // overlong encoding of '/' and lone continuation byte
+        assert_eq!(percent_decode(b"%C0%AF"), Err(DecodingPathErrors::InvalidUtf8));
+        assert_eq!(percent_decode(b"%80"), Err(DecodingPathErrors::InvalidUtf8));
+        assert_eq!(percent_decode(b"\xff"), Err(DecodingPathErrors::InvalidUtf8));
+    }
+}
@@ -0,0 +1,23 @@
+use crate::request::CreatingRequestErrors;
+
+/// defining errors while percent-decoding path or query components
+#[derive(Debug,PartialEq)]
+pub enum DecodingPathErrors {
+    /// when `%` is not followed by two hexadecimal digits
+    InvalidPercentEncoding,
+    /// when decoded bytes are not valid utf-8 ,including overlong and surrogate sequences
+    InvalidUtf8,
+}
+
+#[allow(clippy::from_over_into)]
+impl<T> Into<Result<T,DecodingPathErrors>> for  DecodingPathErrors {
+    fn into(self) -> Result<T, DecodingPathErrors> {
+        Err(self)
+    }
+}
+
+impl From<DecodingPathErrors> for CreatingRequestErrors {
+    fn from(_: DecodingPathErrors) -> Self {
+        CreatingRequestErrors::InvalidHttpFormat
+    }
+}
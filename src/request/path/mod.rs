@@ -0,0 +1,212 @@
+mod errors;
+mod decoding;
+mod query;
+mod segments;
+
+use std::borrow::Cow;
+use std::collections::HashMap;
+use serde::Deserialize;
+use crate::de::{from_query, DeserializingErrors};
+use crate::request::CreatingRequestErrors;
+pub use errors::*;
+/// using percent-decoding functions
+pub use decoding::*;
+/// using allocation free query string api
+pub use query::*;
+/// using path segments iterator
+pub use segments::*;
+
+/// percent-decoded query keys and values
+pub type DecodedQuery<'buf> = HashMap<Cow<'buf,str>,Cow<'buf,str>>;
+
+/// http path structure
+#[derive(Debug)]
+pub struct HttpPath<'buf> {
+    bytes:&'buf [u8],
+}
+
+impl<'buf> HttpPath<'buf> {
+
+    #[cfg(feature = "server")]
+    pub (crate) fn new(bytes:&'buf [u8])->HttpPath<'buf>{
+        HttpPath {
+            bytes
+        }
+    }
+
+    /// converting total path to ['&str']
+    pub fn to_str(&self) -> &'buf str {
+        std::str::from_utf8(self.bytes).unwrap()
+    }
+
+    /// returning the actual bytes of path
+    pub fn get_bytes(&self)->&'buf [u8]{
+        self.bytes
+    }
+
+    /// returning path bytes before `?` without query string
+    pub fn path_bytes(&self)->&'buf [u8]{
+        match self.bytes.iter().position(|b| b == &b'?') {
+            None => {self.bytes}
+            Some(index) => {&self.bytes[..index]}
+        }
+    }
+
+    /// returning query string bytes after the first `?` ,if path contains one
+    pub fn query_bytes(&self)->Option<&'buf [u8]>{
+        self.bytes.iter().position(|b| b == &b'?').map(|index| &self.bytes[index + 1..])
+    }
+
+    /// getting allocation free query view ,which is empty when path has no query string
+    ///
+    /// unlike [`HttpPath::to_query`] repeated keys ,valueless flags and bare `?` are all supported
+    pub fn query_params(&self)->Result<QueryParams<'buf>,DecodingPathErrors>{
+        match std::str::from_utf8(self.query_bytes().unwrap_or_default()) {
+            Ok(query) => {Ok(QueryParams::new(query))}
+            Err(_) => {DecodingPathErrors::InvalidUtf8.into()}
+        }
+    }
+
+    /// deserializing percent-decoded query string into typed struct ,see [`crate::de::from_query`]
+    ///
+    /// path without query string is deserialized like empty query
+    pub fn deserialize_query<T:Deserialize<'buf>>(&self)->Result<T,DeserializingErrors>{
+        from_query(self.query_bytes().unwrap_or_default())
+    }
+
+    /// iterating over percent-encoded segments of path without query string
+    pub fn segments(&self)->Result<PathSegments<'buf>,DecodingPathErrors>{
+        match std::str::from_utf8(self.path_bytes()) {
+            Ok(path) => {Ok(PathSegments::new(path))}
+            Err(_) => {DecodingPathErrors::InvalidUtf8.into()}
+        }
+    }
+
+    /// normalizing path without query string by removing `.` and `..` segments per RFC 3986
+    /// ,and collapsing duplicate slashes like `/a//b` into `/a/b` when `collapse_slashes` is true
+    ///
+    /// result stays percent-encoded and borrows from request bytes when nothing changes
+    /// ,while encoded traversal like `%2e%2e` or `%2f` returns [`CreatingRequestErrors::DangerousInvalidHttpFormat`]
+    pub fn normalize(&self,collapse_slashes:bool)->Result<Cow<'buf,str>,CreatingRequestErrors>{
+        match std::str::from_utf8(self.path_bytes()) {
+            Ok(path) => {normalize_path(path,collapse_slashes)}
+            Err(_) => {CreatingRequestErrors::InvalidHttpFormat.into()}
+        }
+    }
+
+    /// percent-decoding path without query string
+    ///
+    /// borrows from request bytes when path does not contain escapes
+    /// ,note that `%2F` is decoded to `/` so segments should be split before decoding when it matters
+    pub fn decoded_path(&self)->Result<Cow<'buf,str>,DecodingPathErrors>{
+        percent_decode(self.path_bytes())
+    }
+
+    /// like [`HttpPath::to_query`] but with percent-decoded path ,keys and values
+    /// ,where `+` inside query keys and values is decoded as space
+    pub fn to_decoded_query(&self)->Result<Option<(Cow<'buf,str>,DecodedQuery<'buf>)>,DecodingPathErrors>{
+        let query = match self.query_bytes() {
+            None => {return Ok(None)}
+            Some(query) => {query}
+        };
+        let mut map = HashMap::new();
+        for pair in query.split(|b| b == &b'&') {
+            let Some(eq) = pair.iter().position(|b| b == &b'=') else { continue };
+            map.insert(percent_decode_query(&pair[..eq])?,percent_decode_query(&pair[eq + 1..])?);
+        }
+        if map.is_empty() {return Ok(None)}
+        Ok(Some((self.decoded_path()?,map)))
+    }
+
+    /// splitting path into path without query string and raw query pairs
+    /// ,which is `None` when path has no `key=value` pairs or it is not valid utf-8
+    #[inline(always)]
+    pub fn to_query(&self)->Option<(&'buf str,HashMap<&'buf str,&'buf str>)> {
+        let mut map = HashMap::new();
+        // delimiters are ascii ,so slicing path on their indices is always on char boundaries
+        let path = std::str::from_utf8(self.bytes).ok()?;
+        let mut clean_path = path;
+        let mut cursor = 0_usize;
+        let mut key = None;
+        let length = path.len();
+        for (index,q) in path.bytes().enumerate() {
+            match q {
+                b'?'=>{
+                    clean_path = &path[..index];
+                    cursor = index+1;
+                    if cursor >= length { return  None }
+                }
+                b'='=>{
+                    key = Some(&path[cursor..index]);
+                    cursor = index+1;
+                    if cursor >= length {
+                        if map.is_empty() {return  None}
+                        return Some((clean_path,map))
+                    }
+                }
+                b'&'=>{
+                    if let Some(k) = key {
+                        map.insert(k,&path[cursor..index]);
+                        cursor=index+1;
+                        key = None;
+                    }
+                }
+                _=>{}
+            }
+        }
+        if let Some(k ) = key {
+            map.insert(k,&path[cursor..]);
+        }
+        if map.is_empty() {return None}
+        Some((clean_path,map))
+    }
+
+}
+
+#[cfg(all(test, feature = "server"))]
+mod test {
+    use crate::request::HttpPath;
+
+    #[test]
+    fn test_decoded_path_and_query() {
+        let path = HttpPath::new(b"/search%20page?q=rust+http%20server&lang=en&debug");
+        assert_eq!(path.path_bytes(), b"/search%20page");
+        assert_eq!(path.decoded_path().unwrap(), "/search page");
+        let (clean_path, query) = path.to_decoded_query().unwrap().unwrap();
+        assert_eq!(clean_path, "/search page");
+        assert_eq!(query.get("q").unwrap(), "rust http server");
+        assert_eq!(query.get("lang").unwrap(), "en");
+        assert!(HttpPath::new(b"/").to_decoded_query().unwrap().is_none());
+        assert!(HttpPath::new(b"/?q=%").to_decoded_query().is_err());
+    }
+
+    #[test]
+    fn test_query_params() {
+        let path = HttpPath::new(b"/items?tag=a&tag=b&debug&&empty=&page=2");
+        let query = path.query_params().unwrap();
+        assert_eq!(
+            query.iter().collect::<Vec<_>>(),
+            vec![("tag", Some("a")), ("tag", Some("b")), ("debug", None), ("empty", Some("")), ("page", Some("2"))]
+        );
+        assert_eq!(query.get("tag"), Some("a"));
+        assert_eq!(query.get_all("tag").collect::<Vec<_>>(), vec!["a", "b"]);
+        assert_eq!(query.get("missing"), None);
+        assert!(query.contains("debug") && !query.contains("page=2"));
+        assert!(HttpPath::new(b"/items?").query_params().unwrap().is_empty());
+        assert!(HttpPath::new(b"/items").query_params().unwrap().is_empty());
+        assert!(HttpPath::new(b"/items?k=\xff").query_params().is_err());
+        #[derive(serde::Deserialize)]
+        struct Items {
+            tag: Vec<String>,
+            page: u8,
+            debug: bool,
+        }
+        let items: Items = path.deserialize_query().unwrap();
+        assert_eq!((items.tag.len(), items.page, items.debug), (2, 2, true));
+        assert_eq!(path.segments().unwrap().collect::<Vec<_>>(), vec!["items"]);
+        assert_eq!(HttpPath::new(b"/static/../img/./a.png?v=1").normalize(false).unwrap(), "/img/a.png");
+        // map based api keeps working
+        assert_eq!(path.to_query().unwrap().1.get("page"), Some(&"2"));
+        assert!(HttpPath::new(b"/items?k=\xff").to_query().is_none());
+    }
+}
@@ -0,0 +1,108 @@
+/// allocation free view over query string like `tag=a&tag=b&debug`
+///
+/// pairs are kept in their order and repeated keys and valueless flags are preserved
+/// ,keys and values are returned as raw bytes of the request ,so use [`crate::request::percent_decode_query`]
+/// to decode them when needed
+///
+/// # Example
+/// ```
+/// use water_http_utils::request::QueryParams;
+/// let query = QueryParams::new("tag=a&tag=b&debug&page=2");
+/// assert_eq!(query.get("page"),Some("2"));
+/// assert_eq!(query.get_all("tag").collect::<Vec<_>>(),vec!["a","b"]);
+/// assert!(query.contains("debug"));
+/// assert_eq!(query.iter().nth(2),Some(("debug",None)));
+/// ```
+#[derive(Debug,Clone,Copy)]
+pub struct QueryParams<'buf> {
+    query:&'buf str,
+}
+
+impl<'buf> QueryParams<'buf> {
+
+    /// creating query view from query string without the leading `?`
+    pub fn new(query:&'buf str)->QueryParams<'buf>{
+        QueryParams {
+            query
+        }
+    }
+
+    /// returning raw query string
+    pub fn as_str(&self)->&'buf str{
+        self.query
+    }
+
+    /// checking if query does not contain any pair
+    pub fn is_empty(&self)->bool{
+        self.iter().next().is_none()
+    }
+
+    /// iterating over `(key, value)` pairs in order ,where flags like `debug` have no value
+    pub fn iter(&self)->QueryIter<'buf>{
+        QueryIter {
+            rest:self.query,
+        }
+    }
+
+    /// getting value of the first pair with the given key
+    ///
+    /// valueless flags return empty string ,use [`QueryParams::contains`] for checking flags
+    pub fn get(&self,key:&str)->Option<&'buf str>{
+        self.get_all(key).next()
+    }
+
+    /// getting values of all pairs with the given key in order ,like `a` and `b` in `tag=a&tag=b`
+    pub fn get_all<'k>(&self,key:&'k str)->impl Iterator<Item = &'buf str> + 'k where 'buf:'k {
+        self.iter()
+            .filter(move |(k,_)| *k == key)
+            .map(|(_,v)| v.unwrap_or(""))
+    }
+
+    /// checking if query contains the given key with or without value
+    pub fn contains(&self,key:&str)->bool{
+        self.iter().any(|(k,_)| k == key)
+    }
+}
+
+impl<'buf> IntoIterator for QueryParams<'buf> {
+    type Item = (&'buf str,Option<&'buf str>);
+    type IntoIter = QueryIter<'buf>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// iterator over query pairs which is created by [`QueryParams::iter`]
+#[derive(Debug,Clone)]
+pub struct QueryIter<'buf> {
+    rest:&'buf str,
+}
+
+impl<'buf> Iterator for QueryIter<'buf> {
+    type Item = (&'buf str,Option<&'buf str>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if self.rest.is_empty() { return None }
+            let pair = match self.rest.find('&') {
+                None => {
+                    let pair = self.rest;
+                    self.rest = "";
+                    pair
+                }
+                Some(index) => {
+                    let pair = &self.rest[..index];
+                    self.rest = &self.rest[index + 1..];
+                    pair
+                }
+            };
+            // skipping empty pairs like the ones in `a=1&&b=2`
+            if pair.is_empty() { continue }
+            return match pair.find('=') {
+                None => {Some((pair,None))}
+                Some(index) => {Some((&pair[..index],Some(&pair[index + 1..])))}
+            }
+        }
+    }
+}
@@ -0,0 +1,137 @@
+use std::borrow::Cow;
+use crate::request::CreatingRequestErrors;
+
+/// iterator over path segments between slashes which is created by [`crate::request::HttpPath::segments`]
+///
+/// segments are kept percent-encoded and empty segments like the one in `/a//b` or after trailing slash are yielded too
+#[derive(Debug,Clone)]
+pub struct PathSegments<'buf> {
+    rest:Option<&'buf str>,
+}
+
+impl<'buf> PathSegments<'buf> {
+
+    /// creating segments iterator of path without query string
+    pub fn new(path:&'buf str)->PathSegments<'buf>{
+        let path = path.strip_prefix('/').unwrap_or(path);
+        PathSegments {
+            rest:if path.is_empty() {None} else {Some(path)}
+        }
+    }
+}
+
+impl<'buf> Iterator for PathSegments<'buf> {
+    type Item = &'buf str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let rest = self.rest?;
+        match rest.find('/') {
+            None => {
+                self.rest = None;
+                Some(rest)
+            }
+            Some(index) => {
+                self.rest = Some(&rest[index + 1..]);
+                Some(&rest[..index])
+            }
+        }
+    }
+}
+
+/// removing dot-segments of absolute path per RFC 3986 section 5.2.4
+/// and collapsing duplicate slashes when asked ,the path is borrowed when nothing changes
+///
+/// encoded dot-segments like `%2e%2e` and encoded slashes `%2f` are rejected as
+/// [`CreatingRequestErrors::DangerousInvalidHttpFormat`] because later decoding would turn them into traversal
+pub (crate) fn normalize_path(path:&str,collapse_slashes:bool)->Result<Cow<'_,str>,CreatingRequestErrors>{
+    if !path.starts_with('/') { return CreatingRequestErrors::InvalidHttpFormat.into() }
+    let mut output:Vec<&str> = Vec::new();
+    let mut segments = PathSegments::new(path).peekable();
+    while let Some(segment) = segments.next() {
+        if segment.contains('%') && is_encoded_traversal(segment) {
+            return CreatingRequestErrors::DangerousInvalidHttpFormat(None).into()
+        }
+        let is_last = segments.peek().is_none();
+        match segment {
+            "." => {}
+            ".." => {output.pop();}
+            "" if collapse_slashes && !is_last => {continue}
+            segment => {
+                output.push(segment);
+                continue
+            }
+        }
+        // `/a/b/..` becomes `/a/` ,so the directory slash is kept
+        if is_last { output.push(""); }
+    }
+    let mut normalized = String::with_capacity(path.len());
+    for segment in &output {
+        normalized.push('/');
+        normalized.push_str(segment);
+    }
+    if normalized.is_empty() { normalized.push('/'); }
+    if normalized == path { return Ok(Cow::Borrowed(path)) }
+    Ok(Cow::Owned(normalized))
+}
+
+/// checking if segment contains encoded slash or decodes to `.` or `..`
+fn is_encoded_traversal(segment:&str)->bool{
+    let bytes = segment.as_bytes();
+    let mut dots = 0_usize;
+    let mut index = 0_usize;
+    let mut only_dots = true;
+    while index < bytes.len() {
+        let rest = &bytes[index..];
+        if rest.len() >= 3 && rest[0] == b'%' && rest[1] == b'2' {
+            if rest[2].eq_ignore_ascii_case(&b'f') { return true }
+            if rest[2].eq_ignore_ascii_case(&b'e') {
+                dots += 1;
+                index += 3;
+                continue
+            }
+        }
+        if rest[0] == b'.' { dots += 1; } else { only_dots = false; }
+        index += 1;
+    }
+    only_dots && (dots == 1 || dots == 2)
+}
+
+#[cfg(test)]
+mod test {
+    use std::borrow::Cow;
+    use crate::request::{CreatingRequestErrors, PathSegments};
+    use super::normalize_path;
+
+    #[test]
+    fn test_segments() {
+        assert_eq!(PathSegments::new("/a/b%20c//d/").collect::<Vec<_>>(), vec!["a", "b%20c", "", "d", ""]);
+        assert_eq!(PathSegments::new("/").count(), 0);
+    }
+
+    #[test]
+    fn test_remove_dot_segments() {
+        for (path, expected) in [
+            ("/a/b/c/./../../g", "/a/g"),
+            ("/mid/content=5/../6", "/mid/6"),
+            ("/a/b/..", "/a/"),
+            ("/a/./b/.", "/a/b/"),
+            ("/../../etc/passwd", "/etc/passwd"),
+            ("/..", "/"),
+            ("/a//b", "/a//b"),
+        ] {
+            assert_eq!(normalize_path(path, false).unwrap(), expected, "{path}");
+        }
+        assert!(matches!(normalize_path("/a/b/", false), Ok(Cow::Borrowed(_))));
+        assert_eq!(normalize_path("//a///b//", true).unwrap(), "/a/b/");
+        assert_eq!(normalize_path("/a//../b", true).unwrap(), "/b");
+    }
+
+    #[test]
+    fn test_encoded_traversal() {
+        for path in ["/%2e%2e/etc", "/a/%2E./b", "/a/.%2e", "/a/%2e", "/a%2fb", "/a/%2F..%2F"] {
+            assert!(matches!(normalize_path(path, false), Err(CreatingRequestErrors::DangerousInvalidHttpFormat(None))), "{path}");
+        }
+        assert_eq!(normalize_path("/file%2etxt", false).unwrap(), "/file%2etxt");
+        assert!(matches!(normalize_path("*", false), Err(CreatingRequestErrors::InvalidHttpFormat)));
+    }
+}
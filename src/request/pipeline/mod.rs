@@ -0,0 +1,98 @@
+use crate::request::{CreatingRequestErrors, HttpRequest};
+
+/// iterator over successive pipelined requests received in one buffer
+///
+/// every item is a complete request with its whole body available ,and when the buffer ends
+/// in the middle of a request the iterator yields [`CreatingRequestErrors::InsufficientDataSoReadMore`] and stops
+/// ,so the caller could drop [`PipelinedRequests::consumed`] bytes from its buffer and read more
+///
+/// # Example
+/// ```
+/// use water_http_utils::request::HttpRequest;
+/// let bytes = b"GET /a HTTP/1.1\r\nHost: x\r\n\r\nPOST /b HTTP/1.1\r\nHost: x\r\nContent-Length: 2\r\n\r\nhiGET /c";
+/// let mut requests = HttpRequest::<8>::pipelined(bytes);
+/// assert_eq!(requests.next().unwrap().unwrap().path().to_str(),"/a");
+/// assert_eq!(requests.next().unwrap().unwrap().raw_body().unwrap(),b"hi");
+/// assert!(requests.next().unwrap().is_err());
+/// assert!(requests.next().is_none());
+/// assert_eq!(requests.remaining(),b"GET /c");
+/// ```
+#[derive(Debug)]
+pub struct PipelinedRequests<'buf,const HC:usize> {
+    bytes:&'buf [u8],
+    consumed:usize,
+    stopped:bool,
+}
+
+impl<'buf,const HC:usize> PipelinedRequests<'buf,HC> {
+
+    pub (crate) fn new(bytes:&'buf [u8])->PipelinedRequests<'buf,HC>{
+        PipelinedRequests {
+            bytes,
+            consumed:0,
+            stopped:false,
+        }
+    }
+
+    /// returning how many bytes were occupied by the requests yielded so far
+    pub fn consumed(&self)->usize{
+        self.consumed
+    }
+
+    /// returning the bytes after the last yielded request
+    pub fn remaining(&self)->&'buf [u8]{
+        &self.bytes[self.consumed..]
+    }
+}
+
+impl<'buf,const HC:usize> Iterator for PipelinedRequests<'buf,HC> {
+    type Item = Result<HttpRequest<'buf,HC>,CreatingRequestErrors>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.stopped || self.consumed >= self.bytes.len() { return None }
+        // body length is kept in the yielded request ,so calling raw_body later does not decode chunked body again
+        let request = HttpRequest::<HC>::from_bytes(&self.bytes[self.consumed..])
+            .and_then(|mut request| request.resolve_body_length().map(|body_length| (request,body_length)));
+        match request {
+            Ok((request,body_length)) => {
+                self.consumed += request.head_length() + body_length;
+                Some(Ok(request))
+            }
+            Err(e) => {
+                self.stopped = true;
+                Some(Err(e))
+            }
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use crate::request::{CreatingRequestErrors, HttpRequest};
+
+    #[test]
+    fn test_pipelined_requests_with_bodies() {
+        let bytes = b"POST /a HTTP/1.1\r\nHost: x\r\nTransfer-Encoding: chunked\r\n\r\n3\r\nabc\r\n0\r\n\r\nPUT /b HTTP/1.1\r\nHost: x\r\nContent-Length: 3\r\n\r\nxyzDELETE /c HTTP/1.1\r\nHost: x\r\n\r\n";
+        let requests:Vec<_> = HttpRequest::<8>::pipelined(bytes).map(|r| r.unwrap()).collect();
+        assert_eq!(requests.len(), 3);
+        assert_eq!(requests.iter().map(|r| r.body_length).collect::<Vec<_>>(), vec![Some(13), Some(3), Some(0)]);
+        assert_eq!(requests[0].raw_body().unwrap(), b"3\r\nabc\r\n0\r\n\r\n");
+        assert_eq!(requests[1].method(), "PUT");
+        assert_eq!(requests[1].raw_body().unwrap(), b"xyz");
+        assert_eq!(requests[2].path().to_str(), "/c");
+        let total:usize = requests.iter().map(|r| r.consumed_length().unwrap()).sum();
+        assert_eq!(total, bytes.len());
+    }
+
+    #[test]
+    fn test_partial_body_stops_iteration() {
+        let bytes = b"GET /a HTTP/1.1\r\nHost: x\r\n\r\nPOST /b HTTP/1.1\r\nHost: x\r\nContent-Length: 10\r\n\r\nabc";
+        let mut requests = HttpRequest::<8>::pipelined(bytes);
+        assert!(requests.next().unwrap().is_ok());
+        assert!(matches!(requests.next(), Some(Err(CreatingRequestErrors::InsufficientDataSoReadMore))));
+        assert!(requests.next().is_none());
+        assert_eq!(requests.consumed(), 28);
+        assert!(requests.remaining().starts_with(b"POST /b"));
+    }
+}
@@ -0,0 +1,95 @@
+#[cfg(feature = "server")]
+use crate::request::{CreatingRequestErrors, Method};
+use crate::uri::{Authority, Uri};
+
+/// form of request-target in the request first line (RFC 9112 section 3.2)
+#[derive(Debug,Clone,Copy,PartialEq)]
+pub enum RequestTarget<'buf> {
+    /// `/path?query` which is sent to origin servers
+    Origin {
+        /// absolute path
+        path:&'buf str,
+        /// query without the leading `?`
+        query:Option<&'buf str>,
+    },
+    /// `http://example.com/path?query` which is sent to proxies
+    Absolute(Uri<'buf>),
+    /// `host:port` which is only used by `CONNECT` requests
+    Authority(Authority<'buf>),
+    /// `*` which is only used by server wide `OPTIONS` requests
+    Asterisk,
+}
+
+impl<'buf> RequestTarget<'buf> {
+
+    /// classifying request-target of the given method and rejecting forms which are not allowed with it
+    #[cfg(feature = "server")]
+    pub (crate) fn from_first_line(bytes:&'buf [u8],method:&Method<'_>)->Result<RequestTarget<'buf>,CreatingRequestErrors>{
+        if bytes.is_empty() || bytes.iter().any(|b| b < &0x21 || b == &0x7f) { return CreatingRequestErrors::InvalidHttpFormat.into() }
+        let Ok(target) = std::str::from_utf8(bytes) else { return CreatingRequestErrors::InvalidHttpFormat.into() };
+        // fragments are never sent in request-target
+        if target.contains('#') { return CreatingRequestErrors::InvalidHttpFormat.into() }
+        let request_target = if target == "*" {
+            if method != &Method::Options { return CreatingRequestErrors::InvalidHttpFormat.into() }
+            RequestTarget::Asterisk
+        }
+        else if method == &Method::Connect {
+            match Authority::parse(target) {
+                Ok(authority) if !authority.host_str().is_empty() && authority.port().is_some() && authority.userinfo().is_none() => {
+                    RequestTarget::Authority(authority)
+                }
+                _ => {return CreatingRequestErrors::InvalidHttpFormat.into()}
+            }
+        }
+        else if target.starts_with('/') {
+            let (path,query) = match target.find('?') {
+                None => {(target,None)}
+                Some(index) => {(&target[..index],Some(&target[index + 1..]))}
+            };
+            RequestTarget::Origin {path,query}
+        }
+        else {
+            match Uri::parse(target) {
+                Ok(uri) if uri.authority().is_some_and(|a| !a.host_str().is_empty()) => {RequestTarget::Absolute(uri)}
+                _ => {return CreatingRequestErrors::InvalidHttpFormat.into()}
+            }
+        };
+        Ok(request_target)
+    }
+
+    /// getting uri scheme of absolute-form target
+    pub fn scheme(&self)->Option<&'buf str>{
+        match self {
+            RequestTarget::Absolute(uri) => {Some(uri.scheme())}
+            _ => {None}
+        }
+    }
+
+    /// getting authority of absolute-form and authority-form targets
+    pub fn authority(&self)->Option<Authority<'buf>>{
+        match self {
+            RequestTarget::Absolute(uri) => {uri.authority()}
+            RequestTarget::Authority(authority) => {Some(*authority)}
+            _ => {None}
+        }
+    }
+
+    /// getting path of origin-form and absolute-form targets ,where empty absolute path means `/`
+    pub fn path(&self)->Option<&'buf str>{
+        match self {
+            RequestTarget::Origin {path,..} => {Some(path)}
+            RequestTarget::Absolute(uri) if uri.path().is_empty() => {Some("/")}
+            RequestTarget::Absolute(uri) => {Some(uri.path())}
+            _ => {None}
+        }
+    }
+
+    /// getting query of origin-form and absolute-form targets without the leading `?`
+    pub fn query(&self)->Option<&'buf str>{
+        match self {
+            RequestTarget::Origin {query,..} => {*query}
+            RequestTarget::Absolute(uri) => {uri.query()}
+            _ => {None}
+        }
+    }
+}
@@ -0,0 +1,74 @@
+use std::fmt::{Display, Formatter};
+use crate::request::headers::HttpHeaders;
+use crate::utils::trim_ows;
+
+/// http protocol version of HTTP/1.x message syntax
+#[derive(Debug,Clone,Copy,PartialEq,Eq,Hash,PartialOrd,Ord)]
+pub enum Version {
+    /// HTTP/0.9 simple request which is only `GET` with a path and has no version ,headers or body
+    /// ,which is only parsed when [`crate::config::HttpHConfigurations::allow_http09`] is enabled
+    Http09,
+    /// `HTTP/1.0` which closes the connection after each message by default
+    Http10,
+    /// `HTTP/1.1` which keeps the connection open by default
+    Http11,
+}
+
+impl Version {
+
+    /// parsing version token like `HTTP/1.1`
+    ///
+    /// higher minor versions like `HTTP/1.2` are treated as [`Version::Http11`] (RFC 9110 section 2.5)
+    /// ,while other major versions and malformed tokens return [`None`]
+    pub fn from_bytes(bytes:&[u8])->Option<Version>{
+        match bytes {
+            b"HTTP/1.1" => {Some(Version::Http11)}
+            b"HTTP/1.0" => {Some(Version::Http10)}
+            [b'H',b'T',b'T',b'P',b'/',b'1',b'.',minor] if minor.is_ascii_digit() => {Some(Version::Http11)}
+            _ => {None}
+        }
+    }
+
+    /// returning version as it appears in messages ,HTTP/0.9 does not have one so it returns empty string
+    pub fn as_str(&self)->&'static str{
+        match self {
+            Version::Http09 => {""}
+            Version::Http10 => {"HTTP/1.0"}
+            Version::Http11 => {"HTTP/1.1"}
+        }
+    }
+
+    /// checking if connections are persistent unless `Connection: close` is sent
+    pub fn keep_alive_by_default(&self)->bool{
+        matches!(self,Version::Http11)
+    }
+
+    /// checking if messages of this version could use `Transfer-Encoding`
+    pub fn supports_transfer_encoding(&self)->bool{
+        matches!(self,Version::Http11)
+    }
+
+    /// checking if messages of this version could contain header fields
+    pub fn supports_headers(&self)->bool{
+        !matches!(self,Version::Http09)
+    }
+}
+
+impl Display for Version {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// checking if the connection stays open after the message from its version and `Connection` header options
+pub (crate) fn is_keep_alive<const HC:usize>(version:Version,headers:&HttpHeaders<'_,HC>)->bool{
+    let mut keep_alive = version.keep_alive_by_default();
+    for value in headers.get_all("connection") {
+        for option in value.as_bytes().split(|b| b == &b',') {
+            let option = trim_ows(option);
+            if option.eq_ignore_ascii_case(b"close") { return false }
+            if option.eq_ignore_ascii_case(b"keep-alive") { keep_alive = version != Version::Http09; }
+        }
+    }
+    keep_alive
+}
@@ -0,0 +1,214 @@
+use crate::utils::is_field_value_byte;
+use crate::request::Version;
+use crate::writer::{MessageParts, WriteHttpMessage, WritingHttpErrors};
+
+/// building http responses and serializing them into caller buffers without intermediate allocations
+///
+/// # Example
+/// ```
+/// use water_http_utils::response::HttpResponseBuilder;
+/// use water_http_utils::writer::WriteHttpMessage;
+/// let response = HttpResponseBuilder::<8>::new(200).unwrap()
+///     .header("Content-Type","text/plain").unwrap()
+///     .body(b"hello");
+/// let mut buf = [0_u8;128];
+/// let written = response.write_into_slice(&mut buf).unwrap();
+/// assert_eq!(&buf[..written],b"HTTP/1.1 200 OK\r\nContent-Type: text/plain\r\nContent-Length: 5\r\n\r\nhello");
+/// ```
+#[derive(Debug)]
+pub struct HttpResponseBuilder<'a,const HC:usize> {
+    version:Version,
+    status_code:u16,
+    status_code_bytes:[u8;3],
+    reason:&'a str,
+    parts:MessageParts<'a,HC>,
+}
+
+impl<'a,const HC:usize> HttpResponseBuilder<'a,HC> {
+
+    /// creating new response builder with `HTTP/1.1` version and the canonical reason phrase of the status code
+    pub fn new(status_code:u16)->Result<HttpResponseBuilder<'a,HC>,WritingHttpErrors>{
+        if !(100..=999).contains(&status_code) { return WritingHttpErrors::InvalidStatusCode.into() }
+        Ok(
+            HttpResponseBuilder {
+                version:Version::Http11,
+                status_code,
+                status_code_bytes:[
+                    b'0' + (status_code / 100) as u8,
+                    b'0' + (status_code / 10 % 10) as u8,
+                    b'0' + (status_code % 10) as u8,
+                ],
+                reason:canonical_reason(status_code).unwrap_or(""),
+                parts:MessageParts::new(),
+            }
+        )
+    }
+
+    /// changing response version ,HTTP/0.9 is not supported because it does not have headers
+    pub fn version(mut self,version:Version)->Result<Self,WritingHttpErrors>{
+        if !version.supports_headers() { return WritingHttpErrors::InvalidVersion.into() }
+        self.version = version;
+        Ok(self)
+    }
+
+    /// changing response reason phrase
+    pub fn reason(mut self,reason:&'a str)->Result<Self,WritingHttpErrors>{
+        if !reason.bytes().all(is_field_value_byte) { return WritingHttpErrors::InvalidReasonPhrase.into() }
+        self.reason = reason;
+        Ok(self)
+    }
+
+    /// adding new header line
+    pub fn header(self,key:&'a str,value:&'a str)->Result<Self,WritingHttpErrors>{
+        self.header_bytes(key,value.as_bytes())
+    }
+
+    /// adding new header line with raw value bytes
+    pub fn header_bytes(mut self,key:&'a str,value:&'a [u8])->Result<Self,WritingHttpErrors>{
+        self.parts.push_header(key,value)?;
+        Ok(self)
+    }
+
+    /// adding `Transfer-Encoding: chunked` header for streaming the body with [`crate::body::ChunkedEncoder`]
+    /// after writing the response head
+    pub fn chunked(self)->Result<Self,WritingHttpErrors>{
+        self.header("Transfer-Encoding","chunked")
+    }
+
+    /// setting response body
+    ///
+    /// `Content-Length` header is added automatically unless `Content-Length` or `Transfer-Encoding`
+    /// headers were added manually ,and for `1xx` ,`204` and `304` responses the body is never written
+    /// because these responses can not carry content
+    pub fn body(mut self,body:&'a [u8])->Self{
+        self.parts.set_body(body);
+        self
+    }
+
+    fn can_carry_body(&self)->bool{
+        // 304 responses are ended by the empty line after headers even if they describe a body (RFC 9110 section 15.4.5)
+        self.status_code >= 200 && self.status_code != 204 && self.status_code != 304
+    }
+}
+
+impl<const HC:usize> WriteHttpMessage for HttpResponseBuilder<'_,HC> {
+    fn for_each_part<'s,E>(&'s self,f:&mut impl FnMut(&'s [u8])->Result<(),E>)->Result<(),E>{
+        f(self.version.as_str().as_bytes())?;
+        f(b" ")?;
+        f(&self.status_code_bytes)?;
+        f(b" ")?;
+        f(self.reason.as_bytes())?;
+        f(b"\r\n")?;
+        self.parts.for_each_part(self.can_carry_body(),f)
+    }
+}
+
+/// returning the standard reason phrase of the given status code
+pub fn canonical_reason(status_code:u16)->Option<&'static str>{
+    Some(
+        match status_code {
+            100 => {"Continue"}
+            101 => {"Switching Protocols"}
+            200 => {"OK"}
+            201 => {"Created"}
+            202 => {"Accepted"}
+            203 => {"Non-Authoritative Information"}
+            204 => {"No Content"}
+            205 => {"Reset Content"}
+            206 => {"Partial Content"}
+            300 => {"Multiple Choices"}
+            301 => {"Moved Permanently"}
+            302 => {"Found"}
+            303 => {"See Other"}
+            304 => {"Not Modified"}
+            307 => {"Temporary Redirect"}
+            308 => {"Permanent Redirect"}
+            400 => {"Bad Request"}
+            401 => {"Unauthorized"}
+            403 => {"Forbidden"}
+            404 => {"Not Found"}
+            405 => {"Method Not Allowed"}
+            406 => {"Not Acceptable"}
+            408 => {"Request Timeout"}
+            409 => {"Conflict"}
+            410 => {"Gone"}
+            411 => {"Length Required"}
+            412 => {"Precondition Failed"}
+            413 => {"Content Too Large"}
+            414 => {"URI Too Long"}
+            415 => {"Unsupported Media Type"}
+            416 => {"Range Not Satisfiable"}
+            417 => {"Expectation Failed"}
+            421 => {"Misdirected Request"}
+            422 => {"Unprocessable Content"}
+            426 => {"Upgrade Required"}
+            428 => {"Precondition Required"}
+            429 => {"Too Many Requests"}
+            431 => {"Request Header Fields Too Large"}
+            500 => {"Internal Server Error"}
+            501 => {"Not Implemented"}
+            502 => {"Bad Gateway"}
+            503 => {"Service Unavailable"}
+            504 => {"Gateway Timeout"}
+            505 => {"HTTP Version Not Supported"}
+            _ => {return None}
+        }
+    )
+}
+
+
+#[cfg(test)]
+mod test {
+    use std::io::IoSlice;
+    use crate::response::HttpResponseBuilder;
+    use crate::writer::{WriteHttpMessage, WritingHttpErrors};
+
+    #[test]
+    fn test_write_into_vec_and_slice() {
+        let response = HttpResponseBuilder::<4>::new(404).unwrap()
+            .header("Server","water").unwrap()
+            .body(b"missing");
+        let expected = b"HTTP/1.1 404 Not Found\r\nServer: water\r\nContent-Length: 7\r\n\r\nmissing";
+        let mut vec = vec![];
+        assert_eq!(response.write_into_vec(&mut vec), expected.len());
+        assert_eq!(vec, expected);
+        assert_eq!(response.written_length(), expected.len());
+
+        let mut buf = [0_u8;16];
+        assert_eq!(response.write_into_slice(&mut buf), Err(WritingHttpErrors::BufferTooSmall));
+    }
+
+    #[test]
+    fn test_write_io_slices() {
+        let response = HttpResponseBuilder::<4>::new(201).unwrap()
+            .reason("Made").unwrap()
+            .header("Transfer-Encoding","chunked").unwrap();
+        let mut slices:Vec<IoSlice> = vec![];
+        response.write_io_slices(&mut slices);
+        let joined:Vec<u8> = slices.iter().flat_map(|s| s.iter().copied()).collect();
+        assert_eq!(joined, b"HTTP/1.1 201 Made\r\nTransfer-Encoding: chunked\r\n\r\n");
+    }
+
+    #[test]
+    fn test_no_body_for_no_content() {
+        let mut vec = vec![];
+        HttpResponseBuilder::<1>::new(204).unwrap().body(b"ignored").write_into_vec(&mut vec);
+        assert_eq!(vec, b"HTTP/1.1 204 No Content\r\n\r\n");
+        let mut vec = vec![];
+        HttpResponseBuilder::<1>::new(304).unwrap().body(b"ignored").write_into_vec(&mut vec);
+        assert_eq!(vec, b"HTTP/1.1 304 Not Modified\r\n\r\n");
+    }
+
+    #[test]
+    fn test_rejecting_injection() {
+        let builder = HttpResponseBuilder::<2>::new(200).unwrap();
+        assert_eq!(builder.header("X-Test","a\r\nSet-Cookie: b").err(), Some(WritingHttpErrors::InvalidHeaderValue));
+        let builder = HttpResponseBuilder::<2>::new(200).unwrap();
+        assert_eq!(builder.header("X Test","a").err(), Some(WritingHttpErrors::InvalidHeaderName));
+        let builder = HttpResponseBuilder::<2>::new(200).unwrap();
+        assert_eq!(builder.reason("OK\r\n").err(), Some(WritingHttpErrors::InvalidReasonPhrase));
+        assert_eq!(HttpResponseBuilder::<2>::new(42).err(), Some(WritingHttpErrors::InvalidStatusCode));
+        let builder = HttpResponseBuilder::<1>::new(200).unwrap().header("A","b").unwrap();
+        assert_eq!(builder.header("C","d").err(), Some(WritingHttpErrors::MaxHeadersCountReachedOut));
+    }
+}
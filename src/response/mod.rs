@@ -0,0 +1,214 @@
+mod status_line;
+#[cfg(feature = "server")]
+mod builder;
+
+/// using all status line implementations
+pub use status_line::*;
+/// using response builder for writing responses
+#[cfg(feature = "server")]
+pub use builder::*;
+use crate::request::headers::{CreatingHeadersErrors, HttpHeaders, SmugglingReasons};
+use crate::request::{is_keep_alive, Method, Version};
+use crate::body::{BodyFraming, BodyFramingErrors};
+#[cfg(feature = "client")]
+use crate::config::global_config;
+
+/// for parsing http response bytes
+#[derive(Debug)]
+pub struct HttpResponse<'buf,const HC:usize>{
+    status_line: HttpStatusLine<'buf>,
+    headers:HttpHeaders<'buf,HC>,
+}
+
+impl<'buf,const HC:usize> HttpResponse<'buf, HC> {
+
+    /// getting http response version
+    pub fn version(&self)->&'buf str{
+        self.status_line.version
+    }
+
+    /// getting http response version as [`Version`] enum
+    pub fn typed_version(&self)->Version{
+        self.status_line.typed_version
+    }
+
+    /// checking if the connection should stay open after this response
+    /// from its version default and `Connection` header options
+    pub fn keep_alive(&self)->bool{
+        is_keep_alive(self.typed_version(),&self.headers)
+    }
+
+    /// getting http response numeric status code
+    pub fn status_code(&self)->u16{
+        self.status_line.status_code
+    }
+
+    /// getting http response reason phrase
+    pub fn reason(&self)->&'buf str{
+        self.status_line.reason
+    }
+
+    #[inline]
+    /// http status line
+    pub const fn status_line(&self)->&HttpStatusLine<'buf>{
+        &self.status_line
+    }
+
+    #[inline]
+    /// returning all response headers referenced
+    pub const fn headers(&self)->&HttpHeaders<'buf,HC>{
+        &self.headers
+    }
+
+    /// resolving where the response body ends ,which also depends on the method of the request
+    /// this response answers because responses to `HEAD` never have a body
+    pub fn body_framing(&self,request_method:Method<'_>)->Result<BodyFraming,BodyFramingErrors>{
+        BodyFraming::from_response(request_method,self.status_code(),self.typed_version(),&self.headers)
+    }
+
+    /// creating http response structure from bytes received from the server with zero copies
+    #[cfg(feature = "client")]
+    pub fn from_bytes(bytes:&'buf [u8])->Result<HttpResponse<'buf,HC>,CreatingResponseErrors>{
+
+        let status_line = HttpStatusLine::from_client(bytes)?;
+
+        let headers = HttpHeaders::<HC>::parse(&bytes[status_line.status_line_length-1..],global_config(),false)?;
+
+        Ok(
+            HttpResponse {
+                headers,
+                status_line,
+            }
+        )
+    }
+}
+
+
+/// creating response results
+#[derive(Debug)]
+pub enum CreatingResponseErrors{
+    /// for returning invalid http bytes
+    InvalidHttpFormat,
+
+    /// if there is no sufficient data to be valid
+    InsufficientDataSoReadMore,
+
+    /// when the server response looks malicious or abusive
+    /// ,with the reason when headers have ambiguous framing or syntax which could desync the connection
+    DangerousInvalidHttpFormat(Option<SmugglingReasons>),
+    /// when parsing http headers contains errors
+    InvalidHeadersError(CreatingHeadersErrors)
+}
+
+#[allow(clippy::from_over_into)]
+impl<R> Into<Result<R,CreatingResponseErrors>> for CreatingResponseErrors {
+    fn into(self) -> Result<R, CreatingResponseErrors> {
+        Err(self)
+    }
+}
+
+impl From<CreatingHeadersErrors> for CreatingResponseErrors {
+    fn from(value: CreatingHeadersErrors) -> Self {
+        match value {
+            CreatingHeadersErrors::InvalidFormat => {CreatingResponseErrors::InvalidHttpFormat}
+            CreatingHeadersErrors::MaxHeadersSizeReachedOut => {CreatingResponseErrors::DangerousInvalidHttpFormat(None)}
+            CreatingHeadersErrors::ReadMore => { CreatingResponseErrors::InsufficientDataSoReadMore }
+            CreatingHeadersErrors::DangerousInvalidFormat => {CreatingResponseErrors::DangerousInvalidHttpFormat(None)}
+            CreatingHeadersErrors::RequestSmuggling(reason) => {CreatingResponseErrors::DangerousInvalidHttpFormat(Some(reason))}
+            CreatingHeadersErrors::TooManyHeaders => {CreatingResponseErrors::InvalidHeadersError(value)}
+        }
+    }
+}
+
+
+
+#[cfg(all(test, feature = "client"))]
+mod test {
+    use crate::body::BodyFraming;
+    use crate::request::headers::SmugglingReasons;
+    use crate::request::{Method, Version};
+    use crate::response::{CreatingResponseErrors, HttpResponse};
+
+    #[test]
+    fn test_ok_response() {
+        let r_bytes = b"HTTP/1.1 200 OK\r\nContent-Type: text/plain\r\nContent-Length: 5\r\n\r\nhello";
+        let response = HttpResponse::<16>::from_bytes(r_bytes).unwrap();
+        assert_eq!(response.version(), "HTTP/1.1");
+        assert_eq!(response.status_code(), 200);
+        assert_eq!(response.reason(), "OK");
+        assert_eq!(response.headers().content_length, Some(5));
+        assert_eq!(response.headers().get_as_str("content-type"), Some("text/plain"));
+    }
+
+    #[test]
+    fn test_multi_word_reason_and_no_headers() {
+        let r_bytes = b"HTTP/1.0 204 No Content\r\n\r\n";
+        let response = HttpResponse::<4>::from_bytes(r_bytes).unwrap();
+        assert_eq!(response.status_code(), 204);
+        assert_eq!(response.reason(), "No Content");
+        assert!(response.headers().lines().is_empty());
+    }
+
+    #[test]
+    fn test_typed_version() {
+        let response = HttpResponse::<4>::from_bytes(b"HTTP/1.0 200 OK\r\nConnection: keep-alive\r\n\r\n").unwrap();
+        assert_eq!(response.typed_version(), Version::Http10);
+        assert!(response.keep_alive());
+        let response = HttpResponse::<4>::from_bytes(b"HTTP/1.1 200 OK\r\nConnection: close\r\n\r\n").unwrap();
+        assert!(!response.keep_alive());
+        assert!(matches!(HttpResponse::<4>::from_bytes(b"HTTP/9.9garbage 200 OK\r\n\r\n"), Err(CreatingResponseErrors::InvalidHttpFormat)));
+    }
+
+    #[test]
+    fn test_empty_reason() {
+        let response = HttpResponse::<4>::from_bytes(b"HTTP/1.1 404 \r\nServer: x\r\n\r\n").unwrap();
+        assert_eq!(response.status_code(), 404);
+        assert_eq!(response.reason(), "");
+        let response = HttpResponse::<4>::from_bytes(b"HTTP/1.1 500\r\nServer: x\r\n\r\n").unwrap();
+        assert_eq!(response.status_code(), 500);
+    }
+
+    #[test]
+    fn test_response_body_framing() {
+        let response = HttpResponse::<4>::from_bytes(b"HTTP/1.1 200 OK\r\nContent-Length: 5\r\n\r\n").unwrap();
+        assert_eq!(response.body_framing(Method::Get), Ok(BodyFraming::Length(5)));
+        assert_eq!(response.body_framing(Method::Head), Ok(BodyFraming::None));
+        assert_eq!(response.body_framing(Method::Connect), Ok(BodyFraming::None));
+        // methods are case-sensitive ,so lower case extension method is not HEAD
+        assert_eq!(response.body_framing(Method::Extension("head")), Ok(BodyFraming::Length(5)));
+        let response = HttpResponse::<4>::from_bytes(b"HTTP/1.1 200 OK\r\nTransfer-Encoding: chunked, gzip\r\n\r\n").unwrap();
+        assert_eq!(response.body_framing(Method::Get), Ok(BodyFraming::CloseDelimited));
+        let response = HttpResponse::<4>::from_bytes(b"HTTP/1.1 200 OK\r\nConnection: close\r\n\r\n").unwrap();
+        assert_eq!(response.body_framing(Method::Get), Ok(BodyFraming::CloseDelimited));
+        let response = HttpResponse::<4>::from_bytes(b"HTTP/1.1 304 Not Modified\r\nContent-Length: 5\r\n\r\n").unwrap();
+        assert_eq!(response.body_framing(Method::Get), Ok(BodyFraming::None));
+    }
+
+    #[test]
+    fn test_smuggling_is_dangerous() {
+        assert!(matches!(
+            HttpResponse::<4>::from_bytes(b"HTTP/1.1 200 OK\r\nContent-Length : 5\r\n\r\nhello"),
+            Err(CreatingResponseErrors::DangerousInvalidHttpFormat(Some(SmugglingReasons::WhitespaceBeforeColon)))
+        ));
+    }
+
+    #[test]
+    fn test_partial_response() {
+        for r_bytes in [&b"HTTP/1.1 200 O"[..], b"HTTP/1.1 200 OK\r", b"HTTP/1.1 200 OK\r\nServer: x\r\n"] {
+            assert!(matches!(
+                HttpResponse::<4>::from_bytes(r_bytes),
+                Err(CreatingResponseErrors::InsufficientDataSoReadMore)
+            ));
+        }
+    }
+
+    #[test]
+    fn test_invalid_status_line() {
+        for r_bytes in [&b"HTTP/1.1 20 OK\r\n\r\n"[..], b"HTTP/1.1 2000 OK\r\n\r\n", b"HTTP/1.1 abc OK\r\n\r\n", b"FTP/1.1 200 OK\r\n\r\n"] {
+            assert!(matches!(
+                HttpResponse::<4>::from_bytes(r_bytes),
+                Err(CreatingResponseErrors::InvalidHttpFormat)
+            ));
+        }
+    }
+}
@@ -0,0 +1,113 @@
+#[cfg(feature = "client")]
+use crate::config::global_config;
+#[cfg(feature = "client")]
+use crate::response::CreatingResponseErrors;
+use crate::request::Version;
+
+/// head of http response or the status line
+#[derive(Debug)]
+pub struct HttpStatusLine<'buf>{
+    pub(crate) version:&'buf str,
+    pub(crate) typed_version:Version,
+    pub(crate) status_code:u16,
+    pub(crate) reason:&'buf str,
+
+    /// defining status line length
+    pub status_line_length:usize,
+}
+
+
+impl <'buf> HttpStatusLine<'buf> {
+
+    /// parsing status line like `HTTP/1.1 200 OK\r\n` from bytes received from the server
+    /// the reason phrase shares [`crate::config::HttpHConfigurations::max_path_size`] limit
+    /// because it takes the same place as the path in the request first line
+    #[cfg(feature = "client")]
+    #[inline]
+    pub (crate) fn from_client(bytes:&'buf[u8]) -> Result<HttpStatusLine<'buf>,CreatingResponseErrors>{
+        let global_conf = global_config();
+        let max_line_length = global_conf.max_version_size + 5 + global_conf.max_path_size;
+        let total_length = bytes.len();
+        let mut line_end = None;
+        for (index,byte) in bytes.iter().enumerate() {
+            if index >= max_line_length { return CreatingResponseErrors::DangerousInvalidHttpFormat(None).into();}
+            if byte == &b'\r' {
+                let next_index = index + 1;
+                if next_index >= total_length {return CreatingResponseErrors::InsufficientDataSoReadMore.into()}
+                if bytes[next_index] != b'\n' {return CreatingResponseErrors::InvalidHttpFormat.into()}
+                line_end = Some(index);
+                break;
+            }
+        }
+        let line_end = match line_end {
+            None => {return CreatingResponseErrors::InsufficientDataSoReadMore.into()}
+            Some(i) => {i}
+        };
+        let line = &bytes[..line_end];
+
+        let version_end = match line.iter().position(|b| b == &b' ') {
+            None => {return CreatingResponseErrors::InvalidHttpFormat.into()}
+            Some(i) => {i}
+        };
+        if version_end == 0 || version_end >= global_conf.max_version_size {
+            return CreatingResponseErrors::InvalidHttpFormat.into();
+        }
+        let version = &line[..version_end];
+        let typed_version = match Version::from_bytes(version) {
+            None => {return CreatingResponseErrors::InvalidHttpFormat.into()}
+            Some(v) => {v}
+        };
+
+        // status code is always exactly three digits
+        let rest = &line[version_end + 1..];
+        if rest.len() < 3 || !rest[..3].iter().all(|b| b.is_ascii_digit()) {
+            return CreatingResponseErrors::InvalidHttpFormat.into();
+        }
+        let status_code = rest[..3].iter().fold(0_u16,|code,b| code * 10 + (b - b'0') as u16);
+
+        // the reason phrase could be empty and some servers even drop the space before it
+        let reason = match rest.get(3) {
+            None => {&rest[3..]}
+            Some(b' ') => {&rest[4..]}
+            Some(_) => {return CreatingResponseErrors::InvalidHttpFormat.into()}
+        };
+        if reason.iter().any(|b| b.is_ascii_control() && b != &b'\t') {
+            return CreatingResponseErrors::DangerousInvalidHttpFormat(None).into();
+        }
+
+        match (std::str::from_utf8(version),std::str::from_utf8(reason)) {
+            (Ok(version),Ok(reason)) => {
+                Ok(
+                    HttpStatusLine {
+                        version,
+                        typed_version,
+                        status_code,
+                        reason,
+                        status_line_length:line_end + 2,
+                    }
+                )
+            }
+            _ => {CreatingResponseErrors::InvalidHttpFormat.into()}
+        }
+    }
+
+    /// getting http response version
+    pub fn version(&self)->&'buf str{
+        self.version
+    }
+
+    /// getting http response version as [`Version`] enum
+    pub fn typed_version(&self)->Version{
+        self.typed_version
+    }
+
+    /// getting http response numeric status code
+    pub fn status_code(&self)->u16{
+        self.status_code
+    }
+
+    /// getting http response reason phrase
+    pub fn reason(&self)->&'buf str{
+        self.reason
+    }
+}
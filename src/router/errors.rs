@@ -0,0 +1,39 @@
+use crate::request::Method;
+
+/// defining errors while adding route templates to [`crate::router::Router`]
+#[derive(Debug,PartialEq)]
+pub enum InsertingRouteErrors {
+    /// when template does not start with `/` ,has unbalanced braces ,empty parameter names
+    /// or parameters which do not take the whole segment like `/file.{ext}`
+    InvalidTemplate,
+    /// when catch-all parameter like `{*rest}` is not the last segment of template
+    CatchAllNotLast,
+    /// when the same template and method were added before
+    DuplicateRoute,
+    /// when template has more than [`crate::router::MAX_ROUTE_PARAMS`] parameters
+    TooManyParams,
+}
+
+#[allow(clippy::from_over_into)]
+impl<T> Into<Result<T,InsertingRouteErrors>> for  InsertingRouteErrors {
+    fn into(self) -> Result<T, InsertingRouteErrors> {
+        Err(self)
+    }
+}
+
+/// defining errors while matching request path against routes
+#[derive(Debug,PartialEq)]
+pub enum RoutingErrors<'r> {
+    /// when no template matches the path
+    NotFound,
+    /// when path matches a template but not with the requested method
+    /// ,containing the methods which could be sent in `Allow` header
+    MethodNotAllowed(Vec<Method<'r>>),
+}
+
+#[allow(clippy::from_over_into)]
+impl<'r,T> Into<Result<T,RoutingErrors<'r>>> for  RoutingErrors<'r> {
+    fn into(self) -> Result<T, RoutingErrors<'r>> {
+        Err(self)
+    }
+}
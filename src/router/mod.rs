@@ -0,0 +1,255 @@
+mod errors;
+mod node;
+
+pub use errors::*;
+use serde::Deserialize;
+use crate::de::{from_pairs, DeserializingErrors};
+use crate::request::{HttpPath, Method};
+use crate::router::node::{parse_template, Node, Piece, Route};
+pub use node::MAX_ROUTE_PARAMS;
+
+/// route matcher which compiles templates like `/users/{id}/posts/{*rest}` into a radix tree
+///
+/// `{name}` matches one non empty path segment and `{*name}` matches the rest of path
+/// ,static segments are preferred over parameters ,and parameters over catch-all
+///
+/// # Example
+/// ```
+/// use water_http_utils::request::Method;
+/// use water_http_utils::router::{Router, RoutingErrors};
+/// let mut router = Router::new();
+/// router.insert(Method::Get,"/users/{id}",1).unwrap();
+/// router.insert(Method::Get,"/users/{id}/posts/{*rest}",2).unwrap();
+/// router.insert(Method::Post,"/users",3).unwrap();
+/// let matched = router.at(Method::Get,"/users/42/posts/2024/hello").unwrap();
+/// assert_eq!(*matched.handler,2);
+/// assert_eq!(matched.params.get("id"),Some("42"));
+/// assert_eq!(matched.params.get("rest"),Some("2024/hello"));
+/// assert_eq!(router.at(Method::Get,"/users").err(),Some(RoutingErrors::MethodNotAllowed(vec![Method::Post])));
+/// ```
+#[derive(Debug)]
+pub struct Router<T> {
+    root:Node<T>,
+}
+
+impl<T> Default for Router<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Router<T> {
+
+    /// creating router without routes
+    pub fn new()->Router<T>{
+        Router {
+            root:Node::new(""),
+        }
+    }
+
+    /// adding handler for the given method and template
+    ///
+    /// extension methods are borrowed for the router lifetime ,so they are usually string literals
+    /// like `Method::Extension("PROPFIND")`
+    pub fn insert(&mut self,method:Method<'static>,template:&str,handler:T)->Result<(),InsertingRouteErrors>{
+        let pieces = parse_template(template)?;
+        let param_names = pieces.iter().filter_map(|p| match p {
+            Piece::Param(name) | Piece::CatchAll(name) => {Some(name.to_string())}
+            Piece::Static(_) => {None}
+        }).collect();
+        let node = self.root.insert_pieces(&pieces);
+        if node.routes.iter().any(|r| r.method == method) { return InsertingRouteErrors::DuplicateRoute.into() }
+        node.routes.push(
+            Route {
+                method,
+                param_names,
+                handler
+            }
+        );
+        Ok(())
+    }
+
+    /// matching method and path without query string against routes
+    pub fn at<'r,'p>(&'r self,method:Method<'_>,path:&'p str)->Result<RouteMatch<'r,'p,T>,RoutingErrors<'r>>{
+        let mut values = Vec::new();
+        let mut path_match = None;
+        match self.root.lookup(path,method,&mut values,&mut path_match) {
+            Some(route) => {
+                Ok(
+                    RouteMatch {
+                        handler:&route.handler,
+                        params:Params {
+                            names:&route.param_names,
+                            values
+                        }
+                    }
+                )
+            }
+            None => {
+                match path_match {
+                    None => {RoutingErrors::NotFound.into()}
+                    Some(node) => {
+                        RoutingErrors::MethodNotAllowed(node.routes.iter().map(|r| r.method).collect()).into()
+                    }
+                }
+            }
+        }
+    }
+
+    /// matching request path ,ignoring its query string
+    pub fn at_path<'r,'p>(&'r self,method:Method<'_>,path:&HttpPath<'p>)->Result<RouteMatch<'r,'p,T>,RoutingErrors<'r>>{
+        match std::str::from_utf8(path.path_bytes()) {
+            Ok(path) => {self.at(method,path)}
+            Err(_) => {RoutingErrors::NotFound.into()}
+        }
+    }
+}
+
+/// matched route handler and its parameters
+#[derive(Debug)]
+pub struct RouteMatch<'r,'p,T> {
+    /// handler which was added with the matched template
+    pub handler:&'r T,
+    /// path parameters
+    pub params:Params<'r,'p>,
+}
+
+/// path parameters of matched route ,where values are percent-encoded slices of the path
+#[derive(Debug)]
+pub struct Params<'r,'p> {
+    names:&'r [String],
+    values:Vec<&'p str>,
+}
+
+impl<'r,'p> Params<'r,'p> {
+
+    /// getting parameter value by its template name
+    pub fn get(&self,name:&str)->Option<&'p str>{
+        self.iter().find(|(n,_)| *n == name).map(|(_,v)| v)
+    }
+
+    /// iterating over `(name, value)` pairs in template order
+    pub fn iter(&self)->impl Iterator<Item = (&'r str,&'p str)> + '_ {
+        self.names.iter().map(|n| n.as_str()).zip(self.values.iter().copied())
+    }
+
+    /// deserializing parameters into typed struct ,see [`crate::de::from_pairs`]
+    ///
+    /// parameter names are borrowed from the router and values from the path
+    /// ,so borrowed fields could live as long as the shorter of them
+    pub fn deserialize<'a,T:Deserialize<'a>>(&self)->Result<T,DeserializingErrors> where 'r:'a ,'p:'a {
+        from_pairs(self.iter())
+    }
+
+    /// returning parameters count
+    pub fn len(&self)->usize{
+        self.values.len()
+    }
+
+    /// checking if matched template does not have parameters
+    pub fn is_empty(&self)->bool{
+        self.values.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::request::Method;
+use crate::router::{InsertingRouteErrors, Router, RoutingErrors, MAX_ROUTE_PARAMS};
+
+    fn router() -> Router<usize> {
+        let mut router = Router::new();
+        router.insert(Method::Get, "/", 0).unwrap();
+        router.insert(Method::Get, "/users", 1).unwrap();
+        router.insert(Method::Post, "/users", 2).unwrap();
+        router.insert(Method::Get, "/users/new", 3).unwrap();
+        router.insert(Method::Get, "/users/{id}", 4).unwrap();
+        router.insert(Method::Delete, "/users/{user_id}", 5).unwrap();
+        router.insert(Method::Get, "/users/{id}/posts/{post}", 6).unwrap();
+        router.insert(Method::Get, "/user-settings", 7).unwrap();
+        router.insert(Method::Get, "/static/{*rest}", 8).unwrap();
+        router
+    }
+
+    #[test]
+    fn test_matching() {
+        let router = router();
+        for (method, path, handler) in [
+            (Method::Get, "/", 0), (Method::Get, "/users", 1), (Method::Post, "/users", 2), (Method::Get, "/users/new", 3),
+            (Method::Get, "/users/9", 4), (Method::Delete, "/users/new", 5), (Method::Get, "/user-settings", 7),
+        ] {
+            assert_eq!(*router.at(method, path).unwrap().handler, handler, "{method} {path}");
+        }
+        let matched = router.at(Method::Get, "/users/7/posts/hello%20world").unwrap();
+        assert_eq!(matched.params.iter().collect::<Vec<_>>(), vec![("id", "7"), ("post", "hello%20world")]);
+        assert_eq!(router.at(Method::Delete, "/users/3").unwrap().params.get("user_id"), Some("3"));
+        assert_eq!(router.at(Method::Get, "/static/css/app.css").unwrap().params.get("rest"), Some("css/app.css"));
+        assert!(router.at(Method::Get, "/users/new").unwrap().params.is_empty());
+        #[derive(serde::Deserialize)]
+        struct Post {
+            id: u64,
+            post: String,
+        }
+        let post: Post = router.at(Method::Get, "/users/7/posts/intro").unwrap().params.deserialize().unwrap();
+        assert_eq!((post.id, post.post.as_str()), (7, "intro"));
+    }
+
+    #[test]
+    fn test_not_matching() {
+        let router = router();
+        assert_eq!(router.at(Method::Get, "/users/").err(), Some(RoutingErrors::NotFound));
+        assert_eq!(router.at(Method::Get, "/static/").err(), Some(RoutingErrors::NotFound));
+        assert_eq!(router.at(Method::Get, "/users/7/posts").err(), Some(RoutingErrors::NotFound));
+        assert_eq!(router.at(Method::Put, "/users").err(), Some(RoutingErrors::MethodNotAllowed(vec![Method::Get, Method::Post])));
+    }
+
+    #[test]
+    fn test_invalid_templates() {
+        let mut router = router();
+        assert_eq!(router.insert(Method::Get, "/users/{id}", 9), Err(InsertingRouteErrors::DuplicateRoute));
+        assert_eq!(router.insert(Method::Get, "/a/{*rest}/b", 9), Err(InsertingRouteErrors::CatchAllNotLast));
+        for template in ["users", "/a/{}", "/a/{id", "/a/file.{ext}", "/a/{id}x", "/a}"] {
+            assert_eq!(router.insert(Method::Get, template, 9), Err(InsertingRouteErrors::InvalidTemplate), "{template}");
+        }
+    }
+
+    #[test]
+    fn test_extension_methods() {
+        let mut router = Router::new();
+        router.insert(Method::Extension("PROPFIND"), "/files/{*path}", 0).unwrap();
+        let method = Method::from_bytes(b"PROPFIND").unwrap();
+        assert_eq!(*router.at(method, "/files/a/b").unwrap().handler, 0);
+        assert_eq!(router.at(Method::Extension("propfind"), "/files/a").err(), Some(RoutingErrors::MethodNotAllowed(vec![method])));
+    }
+
+    #[test]
+    fn test_bounded_backtracking() {
+        // static and parameter children both match every `/a` segment ,while only the template
+        // with parameters at every level matches the end ,so each level is left through its parameter child
+        let mut router = Router::new();
+        for level in 0..=MAX_ROUTE_PARAMS {
+            let params = (0..level).map(|i| format!("/{{p{i}}}")).collect::<String>();
+            router.insert(Method::Get, &format!("{}{params}/end{level}", "/a".repeat(MAX_ROUTE_PARAMS - level)), level).unwrap();
+        }
+        let path = format!("{}/end{MAX_ROUTE_PARAMS}", "/a".repeat(MAX_ROUTE_PARAMS));
+        let matched = router.at(Method::Get, &path).unwrap();
+        assert_eq!((*matched.handler, matched.params.len()), (MAX_ROUTE_PARAMS, MAX_ROUTE_PARAMS));
+        assert_eq!(*router.at(Method::Get, &format!("{}/end3", "/a".repeat(MAX_ROUTE_PARAMS))).unwrap().handler, 3);
+        assert_eq!(router.at(Method::Get, &format!("{}/none", "/a".repeat(MAX_ROUTE_PARAMS))).err(), Some(RoutingErrors::NotFound));
+        assert_eq!(router.at(Method::Get, &"/a".repeat(10_000)).err(), Some(RoutingErrors::NotFound));
+        let template = (0..=MAX_ROUTE_PARAMS).map(|i| format!("/{{p{i}}}")).collect::<String>();
+        assert_eq!(router.insert(Method::Get, &template, 0), Err(InsertingRouteErrors::TooManyParams));
+    }
+
+    #[test]
+    fn test_multi_byte_static_prefixes() {
+        let mut router = Router::new();
+        router.insert(Method::Get, "/é", 1).unwrap();
+        router.insert(Method::Get, "/è", 2).unwrap();
+        router.insert(Method::Get, "/éa", 3).unwrap();
+        assert_eq!(*router.at(Method::Get, "/é").unwrap().handler, 1);
+        assert_eq!(*router.at(Method::Get, "/è").unwrap().handler, 2);
+        assert_eq!(*router.at(Method::Get, "/éa").unwrap().handler, 3);
+        assert_eq!(router.at(Method::Get, "/ê").err(), Some(RoutingErrors::NotFound));
+    }
+}
@@ -0,0 +1,161 @@
+use crate::request::Method;
+use crate::router::InsertingRouteErrors;
+
+/// maximum parameters count of one template
+///
+/// only parameter and catch-all children are tried again after a failed branch
+/// ,so limiting how many of them one lookup could go through bounds the backtracking on adversarial paths
+pub const MAX_ROUTE_PARAMS:usize = 16;
+
+/// one route handler at the end of template ,with parameter names in their template order
+#[derive(Debug)]
+pub (crate) struct Route<T> {
+    pub (crate) method:Method<'static>,
+    pub (crate) param_names:Vec<String>,
+    pub (crate) handler:T,
+}
+
+/// radix tree node which matches [`Node::prefix`] and continues with its children
+///
+/// static children are tried first ,then the parameter child and at last the catch-all child
+#[derive(Debug)]
+pub (crate) struct Node<T> {
+    prefix:String,
+    children:Vec<Node<T>>,
+    param:Option<Box<Node<T>>>,
+    catch_all:Option<Box<Node<T>>>,
+    pub (crate) routes:Vec<Route<T>>,
+}
+
+/// parsed template piece
+#[derive(Debug,PartialEq)]
+pub (crate) enum Piece<'t> {
+    Static(&'t str),
+    Param(&'t str),
+    CatchAll(&'t str),
+}
+
+impl<T> Node<T> {
+
+    pub (crate) fn new(prefix:&str)->Node<T>{
+        Node {
+            prefix:prefix.to_string(),
+            children:Vec::new(),
+            param:None,
+            catch_all:None,
+            routes:Vec::new(),
+        }
+    }
+
+    /// walking or creating nodes for template pieces and returning the endpoint node
+    pub (crate) fn insert_pieces(&mut self,pieces:&[Piece<'_>])->&mut Node<T>{
+        let mut node = self;
+        for piece in pieces {
+            node = match piece {
+                Piece::Static(s) => {node.insert_static(s)}
+                Piece::Param(_) => {node.param.get_or_insert_with(|| Box::new(Node::new("")))}
+                Piece::CatchAll(_) => {node.catch_all.get_or_insert_with(|| Box::new(Node::new("")))}
+            };
+        }
+        node
+    }
+
+    fn insert_static(&mut self,s:&str)->&mut Node<T>{
+        if s.is_empty() { return self }
+        let position = self.children.iter().position(|child| child.prefix.chars().next() == s.chars().next());
+        let Some(position) = position else {
+            self.children.push(Node::new(s));
+            return self.children.last_mut().unwrap()
+        };
+        let child = &mut self.children[position];
+        let mut common = child.prefix.bytes().zip(s.bytes()).take_while(|(a,b)| a == b).count();
+        // children start with different characters ,but characters could share their first utf-8 bytes
+        // ,so splitting must stay on char boundaries
+        while !s.is_char_boundary(common) { common -= 1 }
+        if common < child.prefix.len() {
+            // splitting child into shared prefix and the old rest
+            let rest = child.prefix.split_off(common);
+            let mut old = Node::new(&rest);
+            std::mem::swap(&mut old.children,&mut child.children);
+            std::mem::swap(&mut old.routes,&mut child.routes);
+            old.param = child.param.take();
+            old.catch_all = child.catch_all.take();
+            child.children.push(old);
+        }
+        child.insert_static(&s[common..])
+    }
+
+    /// finding endpoint node for the rest of path which has a route with the given method
+    /// ,while remembering the first endpoint which matched the path with any method
+    ///
+    /// parameter and catch-all children are skipped after [`MAX_ROUTE_PARAMS`] parameters
+    /// ,because no template could match deeper than that
+    pub (crate) fn lookup<'r,'p>(&'r self,rest:&'p str,method:Method<'_>,params:&mut Vec<&'p str>,path_match:&mut Option<&'r Node<T>>)->Option<&'r Route<T>>{
+        if rest.is_empty() && !self.routes.is_empty() {
+            if let Some(route) = self.routes.iter().find(|r| r.method == method) { return Some(route) }
+            if path_match.is_none() { *path_match = Some(self); }
+        }
+        for child in &self.children {
+            if let Some(next) = rest.strip_prefix(child.prefix.as_str()) {
+                if let Some(route) = child.lookup(next,method,params,path_match) { return Some(route) }
+            }
+        }
+        if params.len() >= MAX_ROUTE_PARAMS { return None }
+        if let Some(param) = &self.param {
+            let end = rest.find('/').unwrap_or(rest.len());
+            if end > 0 {
+                params.push(&rest[..end]);
+                if let Some(route) = param.lookup(&rest[end..],method,params,path_match) { return Some(route) }
+                params.pop();
+            }
+        }
+        if let Some(catch_all) = &self.catch_all {
+            if !rest.is_empty() {
+                params.push(rest);
+                if let Some(route) = catch_all.lookup("",method,params,path_match) { return Some(route) }
+                params.pop();
+            }
+        }
+        None
+    }
+}
+
+/// splitting template like `/users/{id}/posts/{*rest}` into static parts and parameters
+pub (crate) fn parse_template(template:&str)->Result<Vec<Piece<'_>>,InsertingRouteErrors>{
+    if !template.starts_with('/') { return InsertingRouteErrors::InvalidTemplate.into() }
+    let mut pieces = Vec::new();
+    let mut rest = template;
+    while !rest.is_empty() {
+        let Some(open) = rest.find('{') else {
+            if rest.contains('}') { return InsertingRouteErrors::InvalidTemplate.into() }
+            pieces.push(Piece::Static(rest));
+            break
+        };
+        let (before,after) = rest.split_at(open);
+        // parameters must take the whole segment
+        if !before.ends_with('/') || before.contains('}') { return InsertingRouteErrors::InvalidTemplate.into() }
+        pieces.push(Piece::Static(before));
+        let Some(close) = after.find('}') else { return InsertingRouteErrors::InvalidTemplate.into() };
+        let name = &after[1..close];
+        rest = &after[close + 1..];
+        if !rest.is_empty() && !rest.starts_with('/') { return InsertingRouteErrors::InvalidTemplate.into() }
+        match name.strip_prefix('*') {
+            Some(name) => {
+                if !rest.is_empty() { return InsertingRouteErrors::CatchAllNotLast.into() }
+                pieces.push(Piece::CatchAll(name));
+            }
+            None => {pieces.push(Piece::Param(name))}
+        }
+    }
+    let valid_names = pieces.iter().all(|p| match p {
+        Piece::Param(name) | Piece::CatchAll(name) => {
+            !name.is_empty() && !name.contains(['{','}','/','*'])
+        }
+        Piece::Static(_) => {true}
+    });
+    if !valid_names { return InsertingRouteErrors::InvalidTemplate.into() }
+    if pieces.iter().filter(|p| !matches!(p,Piece::Static(_))).count() > MAX_ROUTE_PARAMS {
+        return InsertingRouteErrors::TooManyParams.into()
+    }
+    Ok(pieces)
+}
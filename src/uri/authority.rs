@@ -0,0 +1,121 @@
+use std::net::{Ipv4Addr, Ipv6Addr};
+use crate::uri::{is_sub_delim, is_unreserved, valid_chars, ParsingUriErrors};
+
+/// host part of authority
+#[derive(Debug,Clone,Copy,PartialEq,Eq)]
+pub enum Host<'buf> {
+    /// registered name like `example.com` ,which could contain percent-encoded bytes
+    /// ,and could be empty like in `file:///etc/hosts`
+    RegName(&'buf str),
+    /// dotted decimal ipv4 address like `127.0.0.1`
+    Ipv4(Ipv4Addr),
+    /// bracketed ipv6 literal like `[::1]`
+    Ipv6(Ipv6Addr),
+}
+
+/// zero copy authority like `user:pass@example.com:8080` (RFC 3986 section 3.2)
+#[derive(Debug,Clone,Copy,PartialEq,Eq)]
+pub struct Authority<'buf> {
+    raw:&'buf str,
+    userinfo:Option<&'buf str>,
+    host_str:&'buf str,
+    host:Host<'buf>,
+    port:Option<u16>,
+}
+
+impl<'buf> Authority<'buf> {
+
+    /// parsing authority without the leading `//`
+    ///
+    /// # Example
+    /// ```
+    /// use water_http_utils::uri::{Authority, Host};
+    /// let authority = Authority::parse("[::1]:8080").unwrap();
+    /// assert_eq!(authority.host(),Host::Ipv6(std::net::Ipv6Addr::LOCALHOST));
+    /// assert_eq!(authority.host_str(),"::1");
+    /// assert_eq!(authority.port(),Some(8080));
+    /// ```
+    pub fn parse(raw:&'buf str)->Result<Authority<'buf>,ParsingUriErrors>{
+        let (userinfo,host_port) = match raw.rfind('@') {
+            None => {(None,raw)}
+            Some(index) => {(Some(&raw[..index]),&raw[index + 1..])}
+        };
+        if let Some(userinfo) = userinfo {
+            if !valid_chars(userinfo,|b| is_unreserved(b) || is_sub_delim(b) || b == b':') {
+                return ParsingUriErrors::InvalidUserInfo.into()
+            }
+        }
+        let (host_str,host,port) = if let Some(literal) = host_port.strip_prefix('[') {
+            let Some(end) = literal.find(']') else { return ParsingUriErrors::InvalidHost.into() };
+            let Ok(ip) = literal[..end].parse::<Ipv6Addr>() else { return ParsingUriErrors::InvalidHost.into() };
+            let port = match &literal[end + 1..] {
+                "" => {""}
+                rest => {
+                    match rest.strip_prefix(':') {
+                        None => {return ParsingUriErrors::InvalidHost.into()}
+                        Some(port) => {port}
+                    }
+                }
+            };
+            (&literal[..end],Host::Ipv6(ip),port)
+        }
+        else {
+            let (host_str,port) = match host_port.rfind(':') {
+                None => {(host_port,"")}
+                Some(index) => {(&host_port[..index],&host_port[index + 1..])}
+            };
+            let host = match host_str.parse::<Ipv4Addr>() {
+                Ok(ip) => {Host::Ipv4(ip)}
+                Err(_) => {
+                    if !valid_chars(host_str,|b| is_unreserved(b) || is_sub_delim(b)) {
+                        return ParsingUriErrors::InvalidHost.into()
+                    }
+                    Host::RegName(host_str)
+                }
+            };
+            (host_str,host,port)
+        };
+        // empty port after colon is allowed and means default port
+        let port = if port.is_empty() {None} else {
+            if !port.bytes().all(|b| b.is_ascii_digit()) { return ParsingUriErrors::InvalidPort.into() }
+            match port.parse::<u16>() {
+                Ok(port) => {Some(port)}
+                Err(_) => {return ParsingUriErrors::InvalidPort.into()}
+            }
+        };
+        Ok(
+            Authority {
+                raw,
+                userinfo,
+                host_str,
+                host,
+                port,
+            }
+        )
+    }
+
+    /// returning authority as it was written
+    pub fn as_str(&self)->&'buf str{
+        self.raw
+    }
+
+    /// getting userinfo before `@`
+    pub fn userinfo(&self)->Option<&'buf str>{
+        self.userinfo
+    }
+
+    /// getting parsed host
+    pub fn host(&self)->Host<'buf>{
+        self.host
+    }
+
+    /// getting host as it was written ,without brackets of ipv6 literals
+    pub fn host_str(&self)->&'buf str{
+        self.host_str
+    }
+
+    /// getting port when it exists and is not empty
+    pub fn port(&self)->Option<u16>{
+        self.port
+    }
+}
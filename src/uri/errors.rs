@@ -0,0 +1,25 @@
+/// defining errors while parsing uri or authority
+#[derive(Debug,PartialEq)]
+pub enum ParsingUriErrors {
+    /// when scheme is missing or contains characters other than letters ,digits ,`+` ,`-` and `.`
+    InvalidScheme,
+    /// when userinfo contains invalid characters
+    InvalidUserInfo,
+    /// when host is invalid ip literal or reg-name with invalid characters
+    InvalidHost,
+    /// when port is not a number or does not fit into `u16`
+    InvalidPort,
+    /// when path contains invalid characters
+    InvalidPath,
+    /// when query contains invalid characters
+    InvalidQuery,
+    /// when fragment contains invalid characters
+    InvalidFragment,
+}
+
+#[allow(clippy::from_over_into)]
+impl<T> Into<Result<T,ParsingUriErrors>> for  ParsingUriErrors {
+    fn into(self) -> Result<T, ParsingUriErrors> {
+        Err(self)
+    }
+}
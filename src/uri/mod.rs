@@ -0,0 +1,199 @@
+mod errors;
+mod authority;
+
+pub use errors::*;
+/// using authority and host implementations
+pub use authority::*;
+
+/// zero copy absolute uri like `https://user@example.com:8443/a/b?q=1#top` (RFC 3986)
+///
+/// components are kept percent-encoded as they were written
+///
+/// # Example
+/// ```
+/// use water_http_utils::uri::{Host, Uri};
+/// let uri = Uri::parse("https://user@example.com:8443/a/b?q=1#top").unwrap();
+/// assert_eq!(uri.scheme(),"https");
+/// let authority = uri.authority().unwrap();
+/// assert_eq!(authority.userinfo(),Some("user"));
+/// assert_eq!(authority.host(),Host::RegName("example.com"));
+/// assert_eq!(authority.port(),Some(8443));
+/// assert_eq!(uri.path(),"/a/b");
+/// assert_eq!(uri.query(),Some("q=1"));
+/// assert_eq!(uri.fragment(),Some("top"));
+/// ```
+#[derive(Debug,Clone,Copy,PartialEq,Eq)]
+pub struct Uri<'buf> {
+    raw:&'buf str,
+    scheme:&'buf str,
+    authority:Option<Authority<'buf>>,
+    path:&'buf str,
+    query:Option<&'buf str>,
+    fragment:Option<&'buf str>,
+}
+
+impl<'buf> Uri<'buf> {
+
+    /// parsing absolute uri which must start with scheme
+    pub fn parse(raw:&'buf str)->Result<Uri<'buf>,ParsingUriErrors>{
+        let Some(colon) = raw.find(':') else { return ParsingUriErrors::InvalidScheme.into() };
+        let scheme = &raw[..colon];
+        let mut scheme_bytes = scheme.bytes();
+        let valid_scheme = scheme_bytes.next().is_some_and(|b| b.is_ascii_alphabetic())
+            && scheme_bytes.all(|b| b.is_ascii_alphanumeric() || matches!(b,b'+' | b'-' | b'.'));
+        if !valid_scheme { return ParsingUriErrors::InvalidScheme.into() }
+        let mut rest = &raw[colon + 1..];
+        let fragment = match rest.find('#') {
+            None => {None}
+            Some(index) => {
+                let fragment = &rest[index + 1..];
+                rest = &rest[..index];
+                Some(fragment)
+            }
+        };
+        let query = match rest.find('?') {
+            None => {None}
+            Some(index) => {
+                let query = &rest[index + 1..];
+                rest = &rest[..index];
+                Some(query)
+            }
+        };
+        let authority = match rest.strip_prefix("//") {
+            None => {None}
+            Some(hier) => {
+                let end = hier.find('/').unwrap_or(hier.len());
+                rest = &hier[end..];
+                Some(Authority::parse(&hier[..end])?)
+            }
+        };
+        let path = rest;
+        if !valid_chars(path,|b| is_pchar(b) || b == b'/') { return ParsingUriErrors::InvalidPath.into() }
+        if let Some(query) = query {
+            if !valid_chars(query,|b| is_pchar(b) || b == b'/' || b == b'?') { return ParsingUriErrors::InvalidQuery.into() }
+        }
+        if let Some(fragment) = fragment {
+            if !valid_chars(fragment,|b| is_pchar(b) || b == b'/' || b == b'?') { return ParsingUriErrors::InvalidFragment.into() }
+        }
+        Ok(
+            Uri {
+                raw,
+                scheme,
+                authority,
+                path,
+                query,
+                fragment,
+            }
+        )
+    }
+
+    /// returning uri as it was written
+    pub fn as_str(&self)->&'buf str{
+        self.raw
+    }
+
+    /// getting scheme like `http`
+    pub fn scheme(&self)->&'buf str{
+        self.scheme
+    }
+
+    /// getting authority after `//` ,if uri has one
+    pub fn authority(&self)->Option<Authority<'buf>>{
+        self.authority
+    }
+
+    /// getting path which could be empty like in `http://example.com`
+    pub fn path(&self)->&'buf str{
+        self.path
+    }
+
+    /// getting query without the leading `?`
+    pub fn query(&self)->Option<&'buf str>{
+        self.query
+    }
+
+    /// getting fragment without the leading `#`
+    pub fn fragment(&self)->Option<&'buf str>{
+        self.fragment
+    }
+}
+
+/// checking unreserved characters (RFC 3986 section 2.3)
+#[inline]
+pub (crate) fn is_unreserved(byte:u8)->bool{
+    byte.is_ascii_alphanumeric() || matches!(byte,b'-' | b'.' | b'_' | b'~')
+}
+
+/// checking sub-delims characters (RFC 3986 section 2.2)
+#[inline]
+pub (crate) fn is_sub_delim(byte:u8)->bool{
+    matches!(byte,b'!' | b'$' | b'&' | b'\'' | b'(' | b')' | b'*' | b'+' | b',' | b';' | b'=')
+}
+
+/// checking path characters except percent-encoded triplets (RFC 3986 section 3.3)
+#[inline]
+fn is_pchar(byte:u8)->bool{
+    is_unreserved(byte) || is_sub_delim(byte) || byte == b':' || byte == b'@'
+}
+
+/// checking if all characters are allowed or valid percent-encoded triplets
+pub (crate) fn valid_chars(s:&str,allowed:impl Fn(u8)->bool)->bool{
+    let bytes = s.as_bytes();
+    let mut index = 0;
+    while index < bytes.len() {
+        if bytes[index] == b'%' {
+            let valid = bytes.get(index + 1).is_some_and(|b| b.is_ascii_hexdigit())
+                && bytes.get(index + 2).is_some_and(|b| b.is_ascii_hexdigit());
+            if !valid { return false }
+            index += 3;
+            continue
+        }
+        if !allowed(bytes[index]) { return false }
+        index += 1;
+    }
+    true
+}
+
+#[cfg(test)]
+mod test {
+    use std::net::{Ipv4Addr, Ipv6Addr};
+    use crate::uri::{Authority, Host, ParsingUriErrors, Uri};
+
+    #[test]
+    fn test_components() {
+        let uri = Uri::parse("http://127.0.0.1/a%20b").unwrap();
+        assert_eq!(uri.authority().unwrap().host(), Host::Ipv4(Ipv4Addr::LOCALHOST));
+        assert_eq!(uri.authority().unwrap().port(), None);
+        assert_eq!((uri.path(), uri.query(), uri.fragment()), ("/a%20b", None, None));
+        let uri = Uri::parse("http://[2001:db8::1]:80?x#").unwrap();
+        assert_eq!(uri.authority().unwrap().host(), Host::Ipv6("2001:db8::1".parse::<Ipv6Addr>().unwrap()));
+        assert_eq!((uri.path(), uri.query(), uri.fragment()), ("", Some("x"), Some("")));
+        let uri = Uri::parse("mailto:user@example.com").unwrap();
+        assert_eq!((uri.scheme(), uri.authority(), uri.path()), ("mailto", None, "user@example.com"));
+        let uri = Uri::parse("file:///etc/hosts").unwrap();
+        assert_eq!((uri.authority().unwrap().host(), uri.path()), (Host::RegName(""), "/etc/hosts"));
+        assert_eq!(Authority::parse("example.com:").unwrap().port(), None);
+        assert_eq!(Authority::parse("a:b@256.1.1.1").unwrap().host(), Host::RegName("256.1.1.1"));
+    }
+
+    #[test]
+    fn test_invalid_uris() {
+        for (uri, error) in [
+            ("/relative", ParsingUriErrors::InvalidScheme),
+            ("1http://a", ParsingUriErrors::InvalidScheme),
+            ("http://[::1", ParsingUriErrors::InvalidHost),
+            ("http://[zz]/", ParsingUriErrors::InvalidHost),
+            ("http://[::1]x/", ParsingUriErrors::InvalidHost),
+            ("http://a b/", ParsingUriErrors::InvalidHost),
+            ("http://a:99999/", ParsingUriErrors::InvalidPort),
+            ("http://a:8o/", ParsingUriErrors::InvalidPort),
+            ("http://us[er@a/", ParsingUriErrors::InvalidUserInfo),
+            ("http://a/b c", ParsingUriErrors::InvalidPath),
+            ("http://a/%zz", ParsingUriErrors::InvalidPath),
+            ("http://a/?q=<", ParsingUriErrors::InvalidQuery),
+            ("http://a/#a#b", ParsingUriErrors::InvalidFragment),
+        ] {
+            assert_eq!(Uri::parse(uri).err(), Some(error), "{uri}");
+        }
+    }
+}
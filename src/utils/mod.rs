@@ -1,3 +1,24 @@
 
+/// checking if byte is allowed in http tokens like header names and methods (RFC 9110 section 5.6.2)
+#[inline]
+pub (crate) fn is_token_byte(byte:u8)->bool{
+    matches!(byte,
+        b'!' | b'#' | b'$' | b'%' | b'&' | b'\'' | b'*' | b'+' | b'-' | b'.' |
+        b'^' | b'_' | b'`' | b'|' | b'~' | b'0'..=b'9' | b'a'..=b'z' | b'A'..=b'Z'
+    )
+}
 
+/// checking if byte is allowed inside header value or reason phrase
+/// which means visible characters ,spaces ,tabs and obs-text (RFC 9110 section 5.5)
+#[inline]
+pub (crate) fn is_field_value_byte(byte:u8)->bool{
+    byte == b'\t' || byte == b' ' || (0x21..=0x7e).contains(&byte) || byte >= 0x80
+}
 
+/// trimming optional white spaces (spaces and tabs) from both sides of the given bytes
+#[inline]
+pub (crate) fn trim_ows(bytes:&[u8])->&[u8]{
+    let start = bytes.iter().position(|b| b != &b' ' && b != &b'\t').unwrap_or(bytes.len());
+    let end = bytes.iter().rposition(|b| b != &b' ' && b != &b'\t').map_or(start,|i| i + 1);
+    &bytes[start..end]
+}
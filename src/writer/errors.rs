@@ -0,0 +1,32 @@
+
+/// defining errors while serializing http messages into bytes
+#[derive(Debug,PartialEq)]
+pub enum WritingHttpErrors {
+    /// when header name is empty or contains characters outside the http token grammar
+    InvalidHeaderName,
+    /// when header value contains CR, LF or other control characters
+    InvalidHeaderValue,
+    /// when response reason phrase contains CR, LF or other control characters
+    InvalidReasonPhrase,
+    /// when request method is empty or contains characters outside the http token grammar
+    InvalidMethod,
+    /// when request target is empty or contains spaces or control characters
+    InvalidRequestTarget,
+    /// when response status code is not a three digits code
+    InvalidStatusCode,
+    /// when given http version is not supported
+    InvalidVersion,
+    /// when max headers count reached out
+    MaxHeadersCountReachedOut,
+    /// when writing body data after the terminating chunk was written
+    BodyAlreadyFinished,
+    /// when the caller buffer does not have enough space
+    BufferTooSmall,
+}
+
+#[allow(clippy::from_over_into)]
+impl<T> Into<Result<T,WritingHttpErrors>> for  WritingHttpErrors {
+    fn into(self) -> Result<T, WritingHttpErrors> {
+        Err(self)
+    }
+}
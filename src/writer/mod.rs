@@ -0,0 +1,185 @@
+mod errors;
+
+use std::convert::Infallible;
+use std::io::IoSlice;
+pub use errors::*;
+use crate::utils::{is_field_value_byte, is_token_byte};
+
+/// destination of serialized http bytes
+///
+/// implemented for [`Vec<u8>`] which grows as needed and for [`SliceWriter`]
+/// which writes into a fixed caller buffer and fails when it is full
+pub trait HttpBytesWriter {
+    /// appending bytes to the destination
+    fn write_bytes(&mut self,bytes:&[u8])->Result<(),WritingHttpErrors>;
+}
+
+impl HttpBytesWriter for Vec<u8> {
+    #[inline]
+    fn write_bytes(&mut self, bytes: &[u8]) -> Result<(), WritingHttpErrors> {
+        self.extend_from_slice(bytes);
+        Ok(())
+    }
+}
+
+/// serializing http messages into any destination without intermediate allocations
+///
+/// implemented by [`crate::request::HttpRequestBuilder`] and [`crate::response::HttpResponseBuilder`]
+/// ,which only define their parts while all writing functions are shared
+pub trait WriteHttpMessage {
+    /// passing every serialized part of the message to the given function in order
+    fn for_each_part<'s,E>(&'s self,f:&mut impl FnMut(&'s [u8])->Result<(),E>)->Result<(),E>;
+
+    /// returning total length of serialized message
+    fn written_length(&self)->usize{
+        let mut length = 0_usize;
+        let _ = self.for_each_part::<Infallible>(&mut |part|{ length += part.len(); Ok(())});
+        length
+    }
+
+    /// serializing message into any [`HttpBytesWriter`]
+    fn write_into<W:HttpBytesWriter>(&self,writer:&mut W)->Result<(),WritingHttpErrors>{
+        self.for_each_part(&mut |part| writer.write_bytes(part))
+    }
+
+    /// serializing message into the given buffer and returning written bytes count
+    fn write_into_slice(&self,buf:&mut [u8])->Result<usize,WritingHttpErrors>{
+        let mut writer = SliceWriter::new(buf);
+        self.write_into(&mut writer)?;
+        Ok(writer.written())
+    }
+
+    /// serializing message into the end of the given vector and returning written bytes count
+    fn write_into_vec(&self,vec:&mut Vec<u8>)->usize{
+        let length = self.written_length();
+        vec.reserve(length);
+        let _ = self.for_each_part::<Infallible>(&mut |part|{ vec.extend_from_slice(part); Ok(())});
+        length
+    }
+
+    /// pushing references of all message parts into the given list for vectored writes
+    /// like [`std::io::Write::write_vectored`] without copying any byte
+    fn write_io_slices<'s>(&'s self,slices:&mut Vec<IoSlice<'s>>){
+        let _ = self.for_each_part::<Infallible>(&mut |part|{ slices.push(IoSlice::new(part)); Ok(())});
+    }
+}
+
+/// writing http bytes into a fixed size caller buffer
+#[derive(Debug)]
+pub struct SliceWriter<'a> {
+    buf:&'a mut [u8],
+    position:usize,
+}
+
+impl<'a> SliceWriter<'a> {
+
+    /// creating new writer which starts writing at the beginning of the given buffer
+    pub fn new(buf:&'a mut [u8])->SliceWriter<'a>{
+        SliceWriter {
+            buf,
+            position:0
+        }
+    }
+
+    /// returning how many bytes has been written so far
+    pub fn written(&self)->usize{
+        self.position
+    }
+}
+
+impl HttpBytesWriter for SliceWriter<'_> {
+    #[inline]
+    fn write_bytes(&mut self, bytes: &[u8]) -> Result<(), WritingHttpErrors> {
+        let end = self.position + bytes.len();
+        if end > self.buf.len() { return WritingHttpErrors::BufferTooSmall.into() }
+        self.buf[self.position..end].copy_from_slice(bytes);
+        self.position = end;
+        Ok(())
+    }
+}
+
+/// headers and body parts shared by request and response builders
+#[cfg(any(feature = "server", feature = "client"))]
+#[derive(Debug)]
+pub (crate) struct MessageParts<'a,const HC:usize> {
+    headers:[(&'a str,&'a [u8]);HC],
+    headers_count:usize,
+    has_framing_header:bool,
+    body:Option<&'a [u8]>,
+    content_length:[u8;20],
+    content_length_start:usize,
+}
+
+#[cfg(any(feature = "server", feature = "client"))]
+impl<'a,const HC:usize> MessageParts<'a,HC> {
+
+    pub (crate) fn new()->MessageParts<'a,HC>{
+        MessageParts {
+            headers:[("",&[]);HC],
+            headers_count:0,
+            has_framing_header:false,
+            body:None,
+            content_length:[0;20],
+            content_length_start:20,
+        }
+    }
+
+    pub (crate) fn push_header(&mut self,key:&'a str,value:&'a [u8])->Result<(),WritingHttpErrors>{
+        validate_header_line(key,value)?;
+        if self.headers_count >= HC { return WritingHttpErrors::MaxHeadersCountReachedOut.into() }
+        if key.eq_ignore_ascii_case("content-length") || key.eq_ignore_ascii_case("transfer-encoding") {
+            self.has_framing_header = true;
+        }
+        self.headers[self.headers_count] = (key,value);
+        self.headers_count += 1;
+        Ok(())
+    }
+
+    pub (crate) fn set_body(&mut self,body:&'a [u8]){
+        self.content_length_start = usize_to_bytes(body.len(),&mut self.content_length);
+        self.body = Some(body);
+    }
+
+    /// passing header lines ,the empty line and the body to the given function in order
+    /// and adding `Content-Length` header when body exists and no framing header was added
+    pub (crate) fn for_each_part<'s,E>(&'s self,with_body:bool,f:&mut impl FnMut(&'s [u8])->Result<(),E>)->Result<(),E>{
+        for (key,value) in &self.headers[..self.headers_count] {
+            f(key.as_bytes())?;
+            f(b": ")?;
+            f(value)?;
+            f(b"\r\n")?;
+        }
+        let body = match self.body {
+            Some(body) if with_body => {body}
+            _ => {return f(b"\r\n")}
+        };
+        if !self.has_framing_header {
+            f(b"Content-Length: ")?;
+            f(&self.content_length[self.content_length_start..])?;
+            f(b"\r\n")?;
+        }
+        f(b"\r\n")?;
+        if !body.is_empty() { f(body)?; }
+        Ok(())
+    }
+}
+
+/// writing usize as decimal ascii digits into the end of the given buffer
+/// and returning the index where the digits start
+#[cfg(any(feature = "server", feature = "client"))]
+pub (crate) fn usize_to_bytes(mut value:usize,buf:&mut [u8;20])->usize{
+    let mut start = buf.len();
+    loop {
+        start -= 1;
+        buf[start] = b'0' + (value % 10) as u8;
+        value /= 10;
+        if value == 0 { return start }
+    }
+}
+
+/// validating header name against http token grammar and header value against CR/LF injection
+pub (crate) fn validate_header_line(key:&str,value:&[u8])->Result<(),WritingHttpErrors>{
+    if key.is_empty() || !key.bytes().all(is_token_byte) { return WritingHttpErrors::InvalidHeaderName.into() }
+    if !value.iter().all(|b| is_field_value_byte(*b)) { return WritingHttpErrors::InvalidHeaderValue.into() }
+    Ok(())
+}
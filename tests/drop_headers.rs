@@ -0,0 +1,33 @@
+#![cfg(feature = "server")]
+//! headers overflow policy is read from the global configurations ,so these tests live in their own binary
+//! where changing the configurations can not affect other tests
+
+use std::sync::Once;
+use water_http_utils::config::{set_new_http_configurations, HeadersOverflowPolicy, HttpHConfigurations};
+use water_http_utils::request::headers::CreatingHeadersErrors;
+use water_http_utils::request::{CreatingRequestErrors, HttpRequest};
+
+static DROP: Once = Once::new();
+
+fn drop_policy() {
+    DROP.call_once(|| unsafe {
+        set_new_http_configurations(HttpHConfigurations { headers_overflow_policy: HeadersOverflowPolicy::Drop, ..HttpHConfigurations::default() })
+    });
+}
+
+#[test]
+fn test_dropping_framing_headers_is_rejected() {
+    drop_policy();
+    for r_bytes in [
+        &b"POST / HTTP/1.1\r\nHost: a\r\nX-A: 1\r\nTransfer-Encoding: chunked\r\n\r\n5\r\nhello\r\n0\r\n\r\n"[..],
+        b"POST / HTTP/1.1\r\nHost: a\r\nX-A: 1\r\nContent-Length: 5\r\n\r\nhello",
+    ] {
+        assert!(matches!(HttpRequest::<2>::from_bytes(r_bytes), Err(CreatingRequestErrors::InvalidHeadersError(CreatingHeadersErrors::TooManyHeaders))));
+        let mut requests = HttpRequest::<2>::pipelined(r_bytes);
+        assert!(matches!(requests.next(), Some(Err(CreatingRequestErrors::InvalidHeadersError(CreatingHeadersErrors::TooManyHeaders)))));
+        assert!(requests.next().is_none());
+    }
+    let request = HttpRequest::<2>::from_bytes(b"POST / HTTP/1.1\r\nHost: a\r\nContent-Length: 5\r\nX-A: 1\r\n\r\nhello").unwrap();
+    assert_eq!(request.headers().dropped_count(), 1);
+    assert_eq!(request.consumed_length().unwrap(), 60);
+}
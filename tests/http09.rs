@@ -0,0 +1,38 @@
+#![cfg(feature = "server")]
+//! HTTP/0.9 parsing is enabled from the global configurations ,so these tests live in their own binary
+//! where changing the configurations can not affect other tests
+
+use std::sync::Once;
+use water_http_utils::config::{set_new_http_configurations, HttpHConfigurations};
+use water_http_utils::request::{CreatingRequestErrors, HttpRequest, RequestParser, Version};
+
+static HTTP09: Once = Once::new();
+
+fn allow_http09() {
+    HTTP09.call_once(|| unsafe {
+        set_new_http_configurations(HttpHConfigurations { allow_http09: true, ..HttpHConfigurations::default() })
+    });
+}
+
+#[test]
+fn test_http09_simple_request() {
+    allow_http09();
+    let request = HttpRequest::<4>::from_bytes(b"GET /old\r\n").unwrap();
+    assert_eq!(request.typed_version(), Version::Http09);
+    assert_eq!(request.version(), "");
+    assert_eq!(request.head_length(), 10);
+    assert!(!request.keep_alive());
+
+    let request = RequestParser::new().parse::<8>(b"GET /index.html\r\n").unwrap();
+    assert_eq!(request.typed_version(), Version::Http09);
+    assert_eq!(request.path().to_str(), "/index.html");
+    assert_eq!(request.consumed_length().unwrap(), 17);
+}
+
+#[test]
+fn test_http09_is_only_get_with_origin_form() {
+    allow_http09();
+    for r_bytes in [&b"POST /old\r\n"[..], b"GET http://a/old\r\n", b"GET /old\rx"] {
+        assert!(matches!(HttpRequest::<4>::from_bytes(r_bytes), Err(CreatingRequestErrors::InvalidHttpFormat)));
+    }
+}
@@ -0,0 +1,61 @@
+#![cfg(feature = "server")]
+//! strict mode is read from the global configurations ,so these tests live in their own binary
+//! where changing the configurations can not affect the lenient unit tests
+
+use std::sync::Once;
+use water_http_utils::config::{set_new_http_configurations, HttpHConfigurations};
+use water_http_utils::request::headers::SmugglingReasons;
+use water_http_utils::request::{CreatingRequestErrors, HttpRequest, RequestParser};
+
+static STRICT: Once = Once::new();
+
+fn strict_mode() {
+    STRICT.call_once(|| unsafe {
+        set_new_http_configurations(HttpHConfigurations { strict_mode: true, ..HttpHConfigurations::default() })
+    });
+}
+
+#[test]
+fn test_smuggling_through_from_bytes() {
+    strict_mode();
+    let cases = [
+        (&b"POST / HTTP/1.1\r\nHost: a\r\nContent-Length: 5\r\nContent-Length: 5\r\n\r\nhello"[..], SmugglingReasons::DuplicateContentLength),
+        (b"POST / HTTP/1.1\r\nHost: a\r\nContent-Length: 3\r\nTransfer-Encoding: chunked\r\n\r\n", SmugglingReasons::ContentLengthWithTransferEncoding),
+        (b"POST / HTTP/1.1\r\nHost: a\r\nTransfer-Encoding: chunked, gzip\r\n\r\n", SmugglingReasons::InvalidTransferEncoding),
+        (b"POST / HTTP/1.1\r\nHost: a\r\nContent-Length: +5\r\n\r\nhello", SmugglingReasons::InvalidContentLength),
+    ];
+    for (r_bytes, reason) in cases {
+        match HttpRequest::<8>::from_bytes(r_bytes) {
+            Err(CreatingRequestErrors::DangerousInvalidHttpFormat(Some(r))) => assert_eq!(r, reason),
+            other => panic!("expected {reason:?} but got {other:?}"),
+        }
+        match RequestParser::new().parse::<8>(r_bytes) {
+            Err(CreatingRequestErrors::DangerousInvalidHttpFormat(Some(r))) => assert_eq!(r, reason),
+            other => panic!("expected {reason:?} but got {other:?}"),
+        }
+    }
+    let request = HttpRequest::<8>::from_bytes(b"POST / HTTP/1.1\r\nHost: a\r\nContent-Length: 5\r\n\r\nhello").unwrap();
+    assert_eq!(request.headers().content_length, Some(5));
+}
+
+#[test]
+fn test_host_through_from_bytes() {
+    strict_mode();
+    for r_bytes in [
+        &b"GET / HTTP/1.1\r\n\r\n"[..],
+        b"GET / HTTP/1.1\r\nHost: a\r\nHost: b\r\n\r\n",
+        b"GET / HTTP/1.1\r\nHost: a\r\nHost: a\r\n\r\n",
+        b"GET / HTTP/1.1\r\nHost: user@a\r\n\r\n",
+        b"GET http://a/x HTTP/1.1\r\nHost: b\r\n\r\n",
+    ] {
+        let request = String::from_utf8_lossy(r_bytes);
+        assert!(matches!(HttpRequest::<8>::from_bytes(r_bytes), Err(CreatingRequestErrors::InvalidHostHeader)), "{request}");
+        assert!(matches!(RequestParser::new().parse::<8>(r_bytes), Err(CreatingRequestErrors::InvalidHostHeader)), "{request}");
+    }
+    for r_bytes in [&b"GET / HTTP/1.1\r\nHost: a:80\r\n\r\n"[..], b"GET / HTTP/1.0\r\n\r\n", b"GET http://a/x HTTP/1.1\r\nHost: A\r\n\r\n"] {
+        assert!(HttpRequest::<8>::from_bytes(r_bytes).is_ok(), "{}", String::from_utf8_lossy(r_bytes));
+        assert!(RequestParser::new().parse::<8>(r_bytes).is_ok(), "{}", String::from_utf8_lossy(r_bytes));
+    }
+    let request = HttpRequest::<8>::from_bytes(b"GET / HTTP/1.1\r\nHost: a:80\r\n\r\n").unwrap();
+    assert_eq!(request.host(), Some(("a", Some(80))));
+}